@@ -0,0 +1,90 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Legacy comments have no author account, so the column stays nullable; their
+        // free-text names could have been typed by anyone and are not matched to users
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Comments::UserId).integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-comment-user_id")
+                            .from_tbl(Comments::Table)
+                            .from_col(Comments::UserId)
+                            .to_tbl(Users::Table)
+                            .to_col(Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .drop_column(Comments::UserNameComment)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .add_column(
+                        ColumnDef::new(Comments::UserNameComment)
+                            .string()
+                            .not_null()
+                            .default(""),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"UPDATE comments SET user_name_comment = users.firstname || ' ' || users.lastname
+                FROM users
+                WHERE comments.user_id = users.id"#,
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .drop_foreign_key(Alias::new("fk-comment-user_id"))
+                    .drop_column(Comments::UserId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Comments {
+    Table,
+    UserId,
+    UserNameComment,
+}
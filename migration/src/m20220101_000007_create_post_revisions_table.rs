@@ -0,0 +1,111 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostRevisions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PostRevisions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PostRevisions::PostId).integer().not_null())
+                    .col(ColumnDef::new(PostRevisions::Revision).integer().not_null())
+                    .col(ColumnDef::new(PostRevisions::Title).string().not_null())
+                    .col(ColumnDef::new(PostRevisions::Body).text().not_null())
+                    .col(ColumnDef::new(PostRevisions::Img).string().not_null())
+                    .col(ColumnDef::new(PostRevisions::CategoryId).integer().not_null())
+                    .col(ColumnDef::new(PostRevisions::EditedBy).integer())
+                    .col(
+                        ColumnDef::new(PostRevisions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-post_revision-post_id")
+                            .from(PostRevisions::Table, PostRevisions::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-post_revision-edited_by")
+                            .from(PostRevisions::Table, PostRevisions::EditedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-post_revision-post_id-revision")
+                    .table(PostRevisions::Table)
+                    .col(PostRevisions::PostId)
+                    .col(PostRevisions::Revision)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing posts start their history with their current content
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO post_revisions (post_id, revision, title, body, img, category_id, edited_by) \
+                 SELECT id, 1, title, body, img, category_id, user_id FROM posts",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostRevisions::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum PostRevisions {
+    Table,
+    Id,
+    PostId,
+    Revision,
+    Title,
+    Body,
+    Img,
+    CategoryId,
+    EditedBy,
+    CreatedAt,
+}
@@ -0,0 +1,158 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::Statement};
+use std::collections::HashSet;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Existing rows stored the raw title as slug; normalize them before enforcing uniqueness
+        db.execute_unprepared(
+            "UPDATE posts SET slug = COALESCE(NULLIF(TRIM(BOTH '-' FROM \
+             REGEXP_REPLACE(LOWER(title), '[^a-z0-9]+', '-', 'g')), ''), 'post')",
+        )
+        .await?;
+
+        dedupe_slugs(db).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-post-slug")
+                    .table(Posts::Table)
+                    .col(Posts::Slug)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create post_slug_history table
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostSlugHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PostSlugHistory::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PostSlugHistory::PostId).integer().not_null())
+                    .col(
+                        ColumnDef::new(PostSlugHistory::Slug)
+                            .string()
+                            .unique_key()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PostSlugHistory::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-post_slug_history-post_id")
+                            .from(PostSlugHistory::Table, PostSlugHistory::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostSlugHistory::Table).to_owned())
+            .await?;
+        manager
+            .drop_index(Index::drop().name("idx-post-slug").table(Posts::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Gives every post but the oldest sharing a slug the first free `slug-n`, the
+/// way new posts are numbered, checking each candidate against the slugs
+/// already taken so the unique index can be built.
+async fn dedupe_slugs(db: &SchemaManagerConnection<'_>) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+
+    let duplicates = db
+        .query_all(Statement::from_string(
+            backend,
+            "SELECT id, slug FROM (SELECT id, slug, \
+             ROW_NUMBER() OVER (PARTITION BY slug ORDER BY id) AS rn FROM posts) d \
+             WHERE d.rn > 1 ORDER BY slug, id",
+        ))
+        .await?;
+
+    let mut taken: HashSet<String> = HashSet::new();
+    let mut current_base = None;
+    let mut n = 1;
+
+    for row in duplicates {
+        let id: i32 = row.try_get("", "id")?;
+        let base: String = row.try_get("", "slug")?;
+
+        if current_base.as_ref() != Some(&base) {
+            // Slugs only contain [a-z0-9-] by now, so the pattern needs no escaping
+            taken = db
+                .query_all(Statement::from_sql_and_values(
+                    backend,
+                    "SELECT slug FROM posts WHERE slug LIKE $1",
+                    [format!("{}-%", base).into()],
+                ))
+                .await?
+                .into_iter()
+                .map(|row| row.try_get("", "slug"))
+                .collect::<Result<_, _>>()?;
+            current_base = Some(base.clone());
+            n = 1;
+        }
+
+        let slug = loop {
+            n += 1;
+            let candidate = format!("{}-{}", base, n);
+            if !taken.contains(&candidate) {
+                break candidate;
+            }
+        };
+
+        db.execute(Statement::from_sql_and_values(
+            backend,
+            "UPDATE posts SET slug = $1 WHERE id = $2",
+            [slug.clone().into(), id.into()],
+        ))
+        .await?;
+        taken.insert(slug);
+    }
+
+    Ok(())
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+    Slug,
+}
+
+#[derive(Iden)]
+enum PostSlugHistory {
+    Table,
+    Id,
+    PostId,
+    Slug,
+    CreatedAt,
+}
@@ -0,0 +1,73 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create image_variants table
+        manager
+            .create_table(
+                Table::create()
+                    .table(ImageVariants::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ImageVariants::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ImageVariants::ImageKey).string().not_null())
+                    .col(
+                        ColumnDef::new(ImageVariants::Key)
+                            .string()
+                            .unique_key()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ImageVariants::Width).integer().not_null())
+                    .col(ColumnDef::new(ImageVariants::Height).integer().not_null())
+                    .col(ColumnDef::new(ImageVariants::ContentType).string().not_null())
+                    .col(
+                        ColumnDef::new(ImageVariants::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-image_variants-image_key")
+                    .table(ImageVariants::Table)
+                    .col(ImageVariants::ImageKey)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ImageVariants::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ImageVariants {
+    Table,
+    Id,
+    ImageKey,
+    Key,
+    Width,
+    Height,
+    ContentType,
+    CreatedAt,
+}
@@ -0,0 +1,169 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create media table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Media::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Media::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Media::Hash).string().unique_key().not_null())
+                    .col(ColumnDef::new(Media::Path).string().unique_key().not_null())
+                    .col(ColumnDef::new(Media::FileName).string().not_null())
+                    .col(ColumnDef::new(Media::Mime).string().not_null())
+                    .col(ColumnDef::new(Media::Size).big_integer().not_null())
+                    .col(ColumnDef::new(Media::Width).integer().not_null())
+                    .col(ColumnDef::new(Media::Height).integer().not_null())
+                    .col(ColumnDef::new(Media::UserId).integer().null())
+                    .col(
+                        ColumnDef::new(Media::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-media-user_id")
+                            .from(Media::Table, Media::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Images uploaded before the media library existed have no hash to
+        // record, so their posts keep a NULL media_id and only the `img` key.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(ColumnDef::new(Posts::MediaId).integer().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-posts-media_id")
+                            .from_tbl(Posts::Table)
+                            .from_col(Posts::MediaId)
+                            .to_tbl(Media::Table)
+                            .to_col(Media::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PostRevisions::Table)
+                    .add_column(ColumnDef::new(PostRevisions::MediaId).integer().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-post_revisions-media_id")
+                            .from_tbl(PostRevisions::Table)
+                            .from_col(PostRevisions::MediaId)
+                            .to_tbl(Media::Table)
+                            .to_col(Media::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-posts-media_id")
+                    .table(Posts::Table)
+                    .col(Posts::MediaId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-post_revisions-media_id")
+                    .table(PostRevisions::Table)
+                    .col(PostRevisions::MediaId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PostRevisions::Table)
+                    .drop_foreign_key(Alias::new("fk-post_revisions-media_id"))
+                    .drop_column(PostRevisions::MediaId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_foreign_key(Alias::new("fk-posts-media_id"))
+                    .drop_column(Posts::MediaId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Media::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Media {
+    Table,
+    Id,
+    Hash,
+    Path,
+    FileName,
+    Mime,
+    Size,
+    Width,
+    Height,
+    UserId,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    MediaId,
+}
+
+#[derive(Iden)]
+enum PostRevisions {
+    Table,
+    MediaId,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
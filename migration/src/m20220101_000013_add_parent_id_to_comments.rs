@@ -0,0 +1,93 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Top-level comments have no parent; deleting a comment removes its replies
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Comments::ParentId).integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-comments-parent_id")
+                            .from_tbl(Comments::Table)
+                            .from_col(Comments::ParentId)
+                            .to_tbl(Comments::Table)
+                            .to_col(Comments::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comments-parent_id")
+                    .table(Comments::Table)
+                    .col(Comments::ParentId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comments-id_post_comment")
+                    .table(Comments::Table)
+                    .col(Comments::IdPostComment)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-comments-id_post_comment")
+                    .table(Comments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-comments-parent_id")
+                    .table(Comments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .drop_foreign_key(Alias::new("fk-comments-parent_id"))
+                    .drop_column(Comments::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Comments {
+    Table,
+    Id,
+    IdPostComment,
+    ParentId,
+}
@@ -0,0 +1,171 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::Statement};
+use std::collections::HashSet;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Categories::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Categories::Slug).string())
+                    .add_column_if_not_exists(ColumnDef::new(Categories::Description).text())
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Categories::SortOrder)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Names that only differ in case would break the case-insensitive unique index
+        db.execute_unprepared(
+            "UPDATE categories c SET name = c.name || ' (' || c.id || ')' \
+             FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY LOWER(name) ORDER BY id) AS rn \
+             FROM categories) d \
+             WHERE c.id = d.id AND d.rn > 1",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "UPDATE categories SET slug = COALESCE(NULLIF(TRIM(BOTH '-' FROM \
+             REGEXP_REPLACE(LOWER(name), '[^a-z0-9]+', '-', 'g')), ''), 'category')",
+        )
+        .await?;
+
+        dedupe_slugs(db).await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Categories::Table)
+                    .modify_column(ColumnDef::new(Categories::Slug).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-categories-slug")
+                    .table(Categories::Table)
+                    .col(Categories::Slug)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Expression indexes are not supported by the index builder
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX IF NOT EXISTS \"idx-categories-lower-name\" \
+             ON categories (LOWER(name))",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS \"idx-categories-lower-name\"")
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-categories-slug")
+                    .table(Categories::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Categories::Table)
+                    .drop_column(Categories::Slug)
+                    .drop_column(Categories::Description)
+                    .drop_column(Categories::SortOrder)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Gives every category but the oldest sharing a slug the first free `slug-n`,
+/// the way new categories are numbered, checking each candidate against the slugs
+/// already taken so the unique index can be built.
+async fn dedupe_slugs(db: &SchemaManagerConnection<'_>) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+
+    let duplicates = db
+        .query_all(Statement::from_string(
+            backend,
+            "SELECT id, slug FROM (SELECT id, slug, \
+             ROW_NUMBER() OVER (PARTITION BY slug ORDER BY id) AS rn FROM categories) d \
+             WHERE d.rn > 1 ORDER BY slug, id",
+        ))
+        .await?;
+
+    let mut taken: HashSet<String> = HashSet::new();
+    let mut current_base = None;
+    let mut n = 1;
+
+    for row in duplicates {
+        let id: i32 = row.try_get("", "id")?;
+        let base: String = row.try_get("", "slug")?;
+
+        if current_base.as_ref() != Some(&base) {
+            // Slugs only contain [a-z0-9-] by now, so the pattern needs no escaping
+            taken = db
+                .query_all(Statement::from_sql_and_values(
+                    backend,
+                    "SELECT slug FROM categories WHERE slug LIKE $1",
+                    [format!("{}-%", base).into()],
+                ))
+                .await?
+                .into_iter()
+                .map(|row| row.try_get("", "slug"))
+                .collect::<Result<_, _>>()?;
+            current_base = Some(base.clone());
+            n = 1;
+        }
+
+        let slug = loop {
+            n += 1;
+            let candidate = format!("{}-{}", base, n);
+            if !taken.contains(&candidate) {
+                break candidate;
+            }
+        };
+
+        db.execute(Statement::from_sql_and_values(
+            backend,
+            "UPDATE categories SET slug = $1 WHERE id = $2",
+            [slug.clone().into(), id.into()],
+        ))
+        .await?;
+        taken.insert(slug);
+    }
+
+    Ok(())
+}
+
+#[derive(Iden)]
+enum Categories {
+    Table,
+    Slug,
+    Description,
+    SortOrder,
+}
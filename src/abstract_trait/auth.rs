@@ -2,14 +2,17 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use crate::{domain::{ApiResponse, ErrorResponse, LoginRequest, RegisterRequest, UserResponse}, utils::AppError};
+use crate::{config::Claims, domain::{ApiResponse, LoginRequest, LogoutRequest, RefreshTokenRequest, RegisterRequest, TokenResponse, UserResponse}, utils::AppError};
 
 
 pub type DynAuthService = Arc<dyn AuthServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait AuthServiceTrait {
-    async fn register_user(&self, input: &RegisterRequest) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
-    async fn login_user(&self, input: &LoginRequest) -> Result<ApiResponse<String>, ErrorResponse>;
+    async fn register_user(&self, input: &RegisterRequest) -> Result<ApiResponse<UserResponse>, AppError>;
+    async fn login_user(&self, input: &LoginRequest) -> Result<ApiResponse<TokenResponse>, AppError>;
+    async fn refresh_token(&self, input: &RefreshTokenRequest) -> Result<ApiResponse<TokenResponse>, AppError>;
+    async fn logout_user(&self, claims: &Claims, input: &LogoutRequest) -> Result<ApiResponse<()>, AppError>;
+    async fn is_token_revoked(&self, jti: &str) -> Result<bool, AppError>;
     fn verify_token(&self, token: &str) -> Result<i64, AppError>;
 }
\ No newline at end of file
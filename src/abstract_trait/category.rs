@@ -5,10 +5,11 @@ use sea_orm::DbErr;
 
 use crate::{
     domain::{
-        ApiResponse, ApiResponsePagination, CategoryResponse, CreateCategoryRequest, ErrorResponse,
-        FindAllCategoryRequest, UpdateCategoryRequest,
+        ApiResponse, ApiResponsePagination, CategoryMergeResponse, CategoryResponse,
+        CategoryTreeResponse, CreateCategoryRequest, FindAllCategoryRequest, UpdateCategoryRequest,
     },
     entities::categories,
+    utils::AppError,
 };
 
 pub type DynCategoryRepository = Arc<dyn CategoryRepositoryTrait + Send + Sync>;
@@ -21,11 +22,29 @@ pub trait CategoryRepositoryTrait {
         page: i32,
         page_size: i32,
         search: Option<String>,
-    ) -> Result<(Vec<categories::Model>, i64), DbErr>;
+    ) -> Result<(Vec<(categories::Model, i64)>, i64), DbErr>;
     async fn find_by_id(&self, id: i32) -> Result<Option<categories::Model>, DbErr>;
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<categories::Model>, DbErr>;
+    async fn count_published_posts(&self, id: i32) -> Result<i64, DbErr>;
+    /// Every category, ordered by sort order and then name.
+    async fn find_tree(&self) -> Result<Vec<categories::Model>, DbErr>;
+    /// The given categories together with all of their ancestors.
+    async fn find_ancestors(&self, ids: &[i32]) -> Result<Vec<categories::Model>, DbErr>;
     async fn create(&self, input: &CreateCategoryRequest) -> Result<categories::Model, DbErr>;
-    async fn update(&self, input: &UpdateCategoryRequest) -> Result<categories::Model, DbErr>;
-    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+    /// Returns `None`, changing nothing, when the new parent is the category itself
+    /// or one of its subcategories.
+    async fn update(
+        &self,
+        input: &UpdateCategoryRequest,
+    ) -> Result<Option<categories::Model>, DbErr>;
+    /// Deletes the category unless posts, post revisions or subcategories still
+    /// reference it, and returns how many of each do; all zero means it was deleted.
+    async fn delete(&self, id: i32) -> Result<(u64, u64, u64), DbErr>;
+    /// Moves the posts, post revisions and subcategories of `source_id` to `target_id`
+    /// and deletes `source_id` in one transaction, returning how many of each moved.
+    /// None when `target_id` is `source_id` itself or one of its subcategories.
+    async fn merge(&self, source_id: i32, target_id: i32)
+        -> Result<Option<(u64, u64, u64)>, DbErr>;
 }
 
 #[async_trait]
@@ -33,18 +52,32 @@ pub trait CategoryServiceTrait {
     async fn get_categories(
         &self,
         req: FindAllCategoryRequest,
-    ) -> Result<ApiResponsePagination<Vec<CategoryResponse>>, ErrorResponse>;
+    ) -> Result<ApiResponsePagination<Vec<CategoryResponse>>, AppError>;
     async fn get_category(
         &self,
         id: i32,
-    ) -> Result<Option<ApiResponse<CategoryResponse>>, ErrorResponse>;
+    ) -> Result<Option<ApiResponse<CategoryResponse>>, AppError>;
+    async fn get_category_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<Option<ApiResponse<CategoryResponse>>, AppError>;
+    async fn get_category_tree(&self) -> Result<ApiResponse<Vec<CategoryTreeResponse>>, AppError>;
     async fn create_category(
         &self,
         input: &CreateCategoryRequest,
-    ) -> Result<ApiResponse<CategoryResponse>, ErrorResponse>;
+    ) -> Result<ApiResponse<CategoryResponse>, AppError>;
     async fn update_category(
         &self,
         input: &UpdateCategoryRequest,
-    ) -> Result<Option<ApiResponse<CategoryResponse>>, ErrorResponse>;
-    async fn delete_category(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    ) -> Result<Option<ApiResponse<CategoryResponse>>, AppError>;
+    async fn delete_category(
+        &self,
+        id: i32,
+        reassign_to: Option<i32>,
+    ) -> Result<ApiResponse<Option<CategoryMergeResponse>>, AppError>;
+    async fn merge_category(
+        &self,
+        source_id: i32,
+        target_id: i32,
+    ) -> Result<ApiResponse<CategoryMergeResponse>, AppError>;
 }
@@ -1,11 +1,12 @@
 use std::sync::Arc;
-use sea_orm::DbErr;
+use sea_orm::{prelude::DateTimeWithTimeZone, DbErr};
 use async_trait::async_trait;
 
 use crate::{
-    domain::{ApiResponse, CommentResponse, CreateCommentRequest, ErrorResponse, UpdateCommentRequest},
-    entities::comments,
-    
+    config::Claims,
+    domain::{ApiResponse, ApiResponsePagination, CommentResponse, CommentTreeResponse, CommentSettingsResponse, CreateCommentRequest, FindModerationQueueRequest, FindPostCommentsRequest, ModerateCommentsRequest, UpdateCommentRequest, UpdateCommentSettingsRequest},
+    entities::{comments, sea_orm_active_enums::CommentStatus},
+    utils::AppError,
 };
 
 
@@ -14,21 +15,44 @@ pub type DynCommentService = Arc<dyn CommentServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait CommentRepositoryTrait {
-    async fn find_all(&self) -> Result<Vec<comments::Model>, DbErr>;
+    async fn find_approved(&self) -> Result<Vec<comments::Model>, DbErr>;
     async fn find_by_id(&self, id: i32) -> Result<Option<comments::Model>, DbErr>;
-    async fn create(&self, input: &CreateCommentRequest) -> Result<comments::Model, DbErr>;
-    async fn update(&self, input: &UpdateCommentRequest) -> Result<comments::Model, DbErr>;
+    /// Number of ancestors above the comment, 0 for a top-level comment.
+    async fn find_depth(&self, id: i32) -> Result<Option<i32>, DbErr>;
+    /// Every approved comment reachable from the post's approved top-level
+    /// comments, parents before their replies.
+    async fn find_thread(&self, post_id: i32) -> Result<Vec<comments::Model>, DbErr>;
+    /// Up to `page_size + 1` of the post's approved comments following `req.cursor` in
+    /// `req.sort` order, and the number of comments matching the filters.
+    async fn find_by_post(&self, post_id: i32, req: &FindPostCommentsRequest) -> Result<(Vec<comments::Model>, i64), DbErr>;
+    /// Number of matching comments up to and including `cursor` in `req.sort` order.
+    async fn count_through_cursor(&self, post_id: i32, req: &FindPostCommentsRequest, cursor: i32) -> Result<i64, DbErr>;
+    /// One page of comments in `req.status`, oldest first, and the number of matching comments.
+    async fn find_by_status(&self, req: &FindModerationQueueRequest) -> Result<(Vec<comments::Model>, i64), DbErr>;
+    async fn count_approved_by_user(&self, user_id: i32) -> Result<i64, DbErr>;
+    /// Whether the user posted exactly `comment` at or after `since`.
+    async fn exists_recent_by_user(&self, user_id: i32, comment: &str, since: DateTimeWithTimeZone) -> Result<bool, DbErr>;
+    async fn create(&self, user_id: i32, input: &CreateCommentRequest, status: CommentStatus) -> Result<comments::Model, DbErr>;
+    /// Moves the listed comments to `status`, returning those that exist.
+    async fn update_status(&self, ids: &[i32], status: CommentStatus) -> Result<Vec<comments::Model>, DbErr>;
+    async fn update(&self, input: &UpdateCommentRequest, status: CommentStatus) -> Result<comments::Model, DbErr>;
     async fn delete(&self, id: i32) -> Result<(), DbErr>;
 }
 
 #[async_trait]
 pub trait CommentServiceTrait {
-    async fn get_comments(&self) -> Result<ApiResponse<Vec<CommentResponse>>, ErrorResponse>;
-    async fn get_comment(&self, id: i32) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> ;
-    async fn create_comment(&self, input: &CreateCommentRequest) -> Result<ApiResponse<CommentResponse>, ErrorResponse>;
+    async fn get_comments(&self) -> Result<ApiResponse<Vec<CommentResponse>>, AppError>;
+    async fn get_comment(&self, claims: &Claims, id: i32) -> Result<Option<ApiResponse<CommentResponse>>, AppError> ;
+    async fn get_post_comments(&self, post_id: i32, req: &FindPostCommentsRequest) -> Result<ApiResponsePagination<Vec<CommentResponse>>, AppError>;
+    async fn get_comment_tree(&self, post_id: i32) -> Result<ApiResponse<Vec<CommentTreeResponse>>, AppError>;
+    async fn create_comment(&self, claims: &Claims, input: &CreateCommentRequest) -> Result<ApiResponse<CommentResponse>, AppError>;
     async fn update_comment(
         &self,
+        claims: &Claims,
         input: &UpdateCommentRequest
-    ) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse>;
-    async fn delete_comment(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    ) -> Result<Option<ApiResponse<CommentResponse>>, AppError>;
+    async fn delete_comment(&self, claims: &Claims, id: i32) -> Result<ApiResponse<()>, AppError>;
+    async fn get_moderation_queue(&self, req: &FindModerationQueueRequest) -> Result<ApiResponsePagination<Vec<CommentResponse>>, AppError>;
+    async fn moderate_comments(&self, input: &ModerateCommentsRequest) -> Result<ApiResponse<Vec<CommentResponse>>, AppError>;
+    async fn update_comment_settings(&self, claims: &Claims, post_id: i32, input: &UpdateCommentSettingsRequest) -> Result<ApiResponse<CommentSettingsResponse>, AppError>;
 }
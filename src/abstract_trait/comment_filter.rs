@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{
+    domain::{
+        ApiResponse, CommentFilterRuleResponse, CommentFilterStatsResponse,
+        CreateCommentFilterRuleRequest, CreateCommentRequest, UpdateCommentFilterRuleRequest,
+    },
+    entities::{comment_filter_rules, sea_orm_active_enums::FilterAction},
+    utils::AppError,
+};
+
+pub type DynCommentFilter = Arc<dyn CommentFilter + Send + Sync>;
+pub type DynCommentFilterRepository = Arc<dyn CommentFilterRepositoryTrait + Send + Sync>;
+pub type DynCommentFilterService = Arc<dyn CommentFilterServiceTrait + Send + Sync>;
+
+/// Why a filter objected to a comment and what should happen to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterVerdict {
+    pub action: FilterAction,
+    pub reason: String,
+}
+
+/// One check run against every new comment before it is stored.
+#[async_trait]
+pub trait CommentFilter {
+    /// Stable identifier the hit counters are reported under.
+    fn name(&self) -> &'static str;
+    /// `None` lets the comment through.
+    async fn check(
+        &self,
+        user_id: i32,
+        input: &CreateCommentRequest,
+    ) -> Result<Option<FilterVerdict>, AppError>;
+    /// Called after blocklist rules are created, updated or deleted, so filters
+    /// holding on to them can load them again.
+    async fn rules_changed(&self) {}
+}
+
+#[async_trait]
+pub trait CommentFilterRepositoryTrait {
+    async fn find_all(&self) -> Result<Vec<comment_filter_rules::Model>, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<comment_filter_rules::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateCommentFilterRuleRequest,
+    ) -> Result<comment_filter_rules::Model, DbErr>;
+    async fn update(
+        &self,
+        id: i32,
+        input: &UpdateCommentFilterRuleRequest,
+    ) -> Result<Option<comment_filter_rules::Model>, DbErr>;
+    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+    async fn record_hits(&self, ids: &[i32]) -> Result<(), DbErr>;
+}
+
+#[async_trait]
+pub trait CommentFilterServiceTrait {
+    /// Runs every filter and returns the strictest verdict, spam before hold.
+    async fn evaluate(
+        &self,
+        user_id: i32,
+        input: &CreateCommentRequest,
+    ) -> Result<Option<FilterVerdict>, AppError>;
+    async fn get_stats(&self) -> ApiResponse<Vec<CommentFilterStatsResponse>>;
+    async fn get_rules(&self) -> Result<ApiResponse<Vec<CommentFilterRuleResponse>>, AppError>;
+    async fn create_rule(
+        &self,
+        input: &CreateCommentFilterRuleRequest,
+    ) -> Result<ApiResponse<CommentFilterRuleResponse>, AppError>;
+    async fn update_rule(
+        &self,
+        input: &UpdateCommentFilterRuleRequest,
+    ) -> Result<ApiResponse<CommentFilterRuleResponse>, AppError>;
+    async fn delete_rule(&self, id: i32) -> Result<ApiResponse<()>, AppError>;
+}
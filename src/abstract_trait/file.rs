@@ -1,24 +1,24 @@
 use std::sync::Arc;
 
+use crate::{
+    domain::{DeleteResponse, UploadResponse, UploadedFile},
+    utils::AppError,
+};
 use async_trait::async_trait;
-use axum::{extract::Multipart, http::StatusCode, Json};
-
-use crate::domain::{DeleteResponse, UploadResponse};
 
 pub type DynFileService = Arc<dyn FileServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait FileServiceTrait {
+    /// Stores the image in the media library, or returns the existing entry
+    /// when the same bytes were uploaded before.
     async fn upload_image(
         &self,
-        upload_dir: &str,
-        original_filename: String,
-        content_type: String,
-        file_data: Vec<u8>,
-    ) -> Result<Json<UploadResponse>, (StatusCode, Json<UploadResponse>)>;
-    async fn delete_image(
-        &self,
-        upload_dir: &str,
-        file_name: &str,
-    ) -> Result<Json<DeleteResponse>, (StatusCode, Json<DeleteResponse>)>;
+        file: UploadedFile,
+        user_id: i32,
+    ) -> Result<UploadResponse, AppError>;
+    async fn delete_image(&self, file_name: &str) -> Result<DeleteResponse, AppError>;
+    /// Removes a media entry and its files, refusing while posts or revisions
+    /// still reference it.
+    async fn delete_media(&self, id: i32) -> Result<DeleteResponse, AppError>;
 }
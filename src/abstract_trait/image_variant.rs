@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::entities::image_variants;
+
+pub type DynImageVariantRepository = Arc<dyn ImageVariantRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ImageVariantRepositoryTrait {
+    async fn create(
+        &self,
+        image_key: &str,
+        key: &str,
+        width: i32,
+        height: i32,
+        content_type: &str,
+    ) -> Result<image_variants::Model, DbErr>;
+    /// Returns the variants of every given original image, narrowest first.
+    async fn find_by_image_keys(
+        &self,
+        image_keys: &[String],
+    ) -> Result<Vec<image_variants::Model>, DbErr>;
+    async fn delete_by_image_key(&self, image_key: &str) -> Result<(), DbErr>;
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::{prelude::DateTimeWithTimeZone, DbErr};
+
+use crate::{
+    config::Claims,
+    domain::{
+        ApiResponse, ApiResponsePagination, CreateMediaRequest, FindAllMediaRequest, MediaResponse,
+    },
+    entities::media,
+    utils::AppError,
+};
+
+pub type DynMediaRepository = Arc<dyn MediaRepositoryTrait + Send + Sync>;
+pub type DynMediaService = Arc<dyn MediaServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait MediaRepositoryTrait {
+    async fn find_all(
+        &self,
+        page: i32,
+        page_size: i32,
+        search: Option<String>,
+        mime: Option<String>,
+    ) -> Result<(Vec<media::Model>, i64), DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<media::Model>, DbErr>;
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<media::Model>, DbErr>;
+    async fn create(&self, input: &CreateMediaRequest) -> Result<media::Model, DbErr>;
+    /// Number of posts and post revisions pointing at the media.
+    async fn count_references(&self, id: i32) -> Result<u64, DbErr>;
+    /// Media created before `created_before` that no post or revision points at.
+    async fn find_unreferenced(
+        &self,
+        created_before: DateTimeWithTimeZone,
+    ) -> Result<Vec<media::Model>, DbErr>;
+    /// Storage key of every media entry.
+    async fn find_paths(&self) -> Result<Vec<String>, DbErr>;
+    /// Distinct `img` values of all posts and revisions, including legacy
+    /// images that predate the media library.
+    async fn find_image_references(&self) -> Result<Vec<String>, DbErr>;
+    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+}
+
+#[async_trait]
+pub trait MediaServiceTrait {
+    async fn get_media_list(
+        &self,
+        req: FindAllMediaRequest,
+    ) -> Result<ApiResponsePagination<Vec<MediaResponse>>, AppError>;
+    async fn get_media(&self, id: i32) -> Result<ApiResponse<MediaResponse>, AppError>;
+    async fn delete_media(&self, claims: &Claims, id: i32) -> Result<ApiResponse<()>, AppError>;
+}
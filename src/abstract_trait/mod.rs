@@ -1,8 +1,16 @@
 mod auth;
 mod category;
 mod comment;
+mod comment_filter;
 mod file;
+mod image_variant;
+mod media;
 mod post;
+mod scheduler;
+mod storage;
+mod tag;
+mod token;
+mod upload_gc;
 mod user;
 
 pub use self::category::{
@@ -17,8 +25,29 @@ pub use self::comment::{
     CommentRepositoryTrait, CommentServiceTrait, DynCommentRepository, DynCommentService,
 };
 
+pub use self::comment_filter::{
+    CommentFilter, CommentFilterRepositoryTrait, CommentFilterServiceTrait, DynCommentFilter,
+    DynCommentFilterRepository, DynCommentFilterService, FilterVerdict,
+};
+
 pub use self::user::{DynUserRepository, DynUserService, UserRepositoryTrait, UserServiceTrait};
 
 pub use self::auth::{AuthServiceTrait, DynAuthService};
 
 pub use self::file::{DynFileService, FileServiceTrait};
+
+pub use self::image_variant::{DynImageVariantRepository, ImageVariantRepositoryTrait};
+
+pub use self::media::{
+    DynMediaRepository, DynMediaService, MediaRepositoryTrait, MediaServiceTrait,
+};
+
+pub use self::scheduler::{DynPublishScheduler, PublishSchedulerTrait};
+
+pub use self::storage::{DynStorageBackend, StorageBackend, StoredObject};
+
+pub use self::tag::{DynTagRepository, DynTagService, TagRepositoryTrait, TagServiceTrait};
+
+pub use self::token::{DynTokenRepository, TokenRepositoryTrait};
+
+pub use self::upload_gc::{DynUploadGc, UploadGcTrait};
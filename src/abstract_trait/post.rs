@@ -1,14 +1,18 @@
 use std::sync::Arc;
 
 use crate::{
+    config::Claims,
     domain::{
-        ApiResponse, ApiResponsePagination, CreatePostRequest, ErrorResponse, FindAllPostRequest,
-        PostRelationResponse, PostResponse, UpdatePostRequest,
+        ApiResponse, ApiResponsePagination, CreatePostRequest, FindAllPostRequest,
+        PostFilter, PostRelationResponse, PostResponse, PostRevisionResponse, PostSlugLookup,
+        PostTransition,
+        RevisionDiffResponse, SchedulePostRequest, UpdatePostRequest,
     },
-    entities::posts,
+    entities::{post_revisions, posts, sea_orm_active_enums::PostStatus},
+    utils::AppError,
 };
 use async_trait::async_trait;
-use sea_orm::DbErr;
+use sea_orm::{prelude::DateTimeWithTimeZone, DbErr};
 
 pub type DynPostsRepository = Arc<dyn PostsRepositoryTrait + Send + Sync>;
 pub type DynPostsService = Arc<dyn PostsServiceTrait + Send + Sync>;
@@ -19,12 +23,72 @@ pub trait PostsRepositoryTrait {
         &self,
         page: i32,
         page_size: i32,
-        search: Option<String>,
+        filter: &PostFilter,
     ) -> Result<(Vec<posts::Model>, i64), DbErr>;
+    /// Full-text search; returns each post with its `ts_rank` and `ts_headline` snippet.
+    async fn search_posts(
+        &self,
+        page: i32,
+        page_size: i32,
+        filter: &PostFilter,
+        tsquery: &str,
+    ) -> Result<(Vec<(posts::Model, f32, String)>, i64), DbErr>;
     async fn get_post(&self, post_id: i32) -> Result<Option<posts::Model>, DbErr>;
+    async fn get_post_by_slug(&self, slug: &str) -> Result<Option<posts::Model>, DbErr>;
+    /// Finds the post that used to be reachable under `slug` before a title change.
+    async fn get_post_by_previous_slug(&self, slug: &str) -> Result<Option<posts::Model>, DbErr>;
     async fn get_post_relation(&self, post_id: i32) -> Result<Vec<PostRelationResponse>, DbErr>;
-    async fn create_post(&self, input: &CreatePostRequest) -> Result<posts::Model, DbErr>;
-    async fn update_post(&self, input: &UpdatePostRequest) -> Result<posts::Model, DbErr>;
+    async fn create_post(
+        &self,
+        input: &CreatePostRequest,
+        user_id: i32,
+        user_name: &str,
+    ) -> Result<posts::Model, DbErr>;
+    /// Updates the post and records the new content as a revision in the same transaction.
+    async fn update_post(
+        &self,
+        input: &UpdatePostRequest,
+        edited_by: i32,
+    ) -> Result<posts::Model, DbErr>;
+    async fn get_revisions(&self, post_id: i32) -> Result<Vec<post_revisions::Model>, DbErr>;
+    async fn get_revision(
+        &self,
+        post_id: i32,
+        revision: i32,
+    ) -> Result<Option<post_revisions::Model>, DbErr>;
+    /// Copies an older revision back onto the post as a new revision.
+    async fn restore_revision(
+        &self,
+        post_id: i32,
+        revision: i32,
+        edited_by: i32,
+    ) -> Result<Option<posts::Model>, DbErr>;
+    /// Moves a post to `status` only if it is currently in one of `from`.
+    async fn update_status(
+        &self,
+        post_id: i32,
+        from: &[PostStatus],
+        status: PostStatus,
+        published_at: Option<DateTimeWithTimeZone>,
+    ) -> Result<Option<posts::Model>, DbErr>;
+    /// Marks a post as scheduled for `publish_at` if it is currently in one of `from`.
+    async fn schedule_post(
+        &self,
+        post_id: i32,
+        from: &[PostStatus],
+        publish_at: DateTimeWithTimeZone,
+    ) -> Result<Option<posts::Model>, DbErr>;
+    /// Publishes up to `limit` scheduled posts whose `publish_at` is not after `now`.
+    async fn publish_due_posts(
+        &self,
+        now: DateTimeWithTimeZone,
+        limit: u64,
+    ) -> Result<Vec<posts::Model>, DbErr>;
+    async fn set_comments_require_approval(
+        &self,
+        post_id: i32,
+        require_approval: bool,
+    ) -> Result<Option<posts::Model>, DbErr>;
     async fn delete_post(&self, post_id: i32) -> Result<(), DbErr>;
 }
 
@@ -32,23 +96,73 @@ pub trait PostsRepositoryTrait {
 pub trait PostsServiceTrait {
     async fn get_all_posts(
         &self,
+        claims: Option<&Claims>,
         req: FindAllPostRequest,
-    ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse>;
+    ) -> Result<ApiResponsePagination<Vec<PostResponse>>, AppError>;
     async fn get_post(
         &self,
+        claims: Option<&Claims>,
         post_id: i32,
-    ) -> Result<Option<ApiResponse<PostResponse>>, ErrorResponse>;
+    ) -> Result<Option<ApiResponse<PostResponse>>, AppError>;
+    async fn get_post_by_slug(
+        &self,
+        claims: Option<&Claims>,
+        slug: &str,
+    ) -> Result<PostSlugLookup, AppError>;
     async fn get_post_relation(
         &self,
+        claims: Option<&Claims>,
         post_id: i32,
-    ) -> Result<ApiResponse<PostRelationResponse>, ErrorResponse>;
+    ) -> Result<ApiResponse<PostRelationResponse>, AppError>;
     async fn create_post(
         &self,
+        claims: &Claims,
         input: &CreatePostRequest,
-    ) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
+    ) -> Result<ApiResponse<PostResponse>, AppError>;
     async fn update_post(
         &self,
+        claims: &Claims,
         input: &UpdatePostRequest,
-    ) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
-    async fn delete_post(&self, post_id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    ) -> Result<ApiResponse<PostResponse>, AppError>;
+    async fn transition_post(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        transition: PostTransition,
+    ) -> Result<ApiResponse<PostResponse>, AppError>;
+    async fn schedule_post(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        input: &SchedulePostRequest,
+    ) -> Result<ApiResponse<PostResponse>, AppError>;
+    async fn get_post_revisions(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+    ) -> Result<ApiResponse<Vec<PostRevisionResponse>>, AppError>;
+    async fn get_post_revision(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        revision: i32,
+    ) -> Result<ApiResponse<PostRevisionResponse>, AppError>;
+    async fn diff_post_revisions(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        from: i32,
+        to: i32,
+    ) -> Result<ApiResponse<RevisionDiffResponse>, AppError>;
+    async fn restore_post_revision(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        revision: i32,
+    ) -> Result<ApiResponse<PostResponse>, AppError>;
+    async fn delete_post(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+    ) -> Result<ApiResponse<()>, AppError>;
 }
@@ -0,0 +1,17 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    domain::{ApiResponse, SchedulerStatusResponse},
+    utils::AppError,
+};
+use async_trait::async_trait;
+
+pub type DynPublishScheduler = Arc<dyn PublishSchedulerTrait + Send + Sync>;
+
+#[async_trait]
+pub trait PublishSchedulerTrait {
+    fn interval(&self) -> Duration;
+    /// Publishes every post whose scheduled time has passed and returns how many went live.
+    async fn run_once(&self) -> Result<u64, AppError>;
+    async fn status(&self) -> ApiResponse<SchedulerStatusResponse>;
+}
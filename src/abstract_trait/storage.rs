@@ -0,0 +1,35 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::utils::AppError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+pub type DynStorageBackend = Arc<dyn StorageBackend + Send + Sync>;
+
+#[derive(Debug, Clone)]
+pub struct StoredObject {
+    pub key: String,
+    pub size: u64,
+    pub last_modified: DateTime<Utc>,
+}
+
+/// Where uploaded objects live. Keys are `/`-separated relative paths such as
+/// `2025-01-31/<uuid>.png`.
+#[async_trait]
+pub trait StorageBackend {
+    /// Stores the file at `source` under `key`; `source` may be moved away.
+    async fn put(&self, key: &str, source: &Path, content_type: &str) -> Result<(), AppError>;
+    async fn delete(&self, key: &str) -> Result<(), AppError>;
+    async fn exists(&self, key: &str) -> Result<bool, AppError>;
+    /// A URL clients can download `key` from, either proxied by this app or presigned.
+    async fn url(&self, key: &str) -> Result<String, AppError>;
+    /// Every stored object, in no particular order.
+    async fn list(&self) -> Result<Vec<StoredObject>, AppError>;
+    /// The file backing `key` when the backend keeps objects on local disk.
+    fn local_path(&self, _key: &str) -> Result<Option<PathBuf>, AppError> {
+        Ok(None)
+    }
+}
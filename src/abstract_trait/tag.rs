@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{
+    domain::{
+        ApiResponse, ApiResponsePagination, CreateTagRequest, FindAllTagRequest, TagResponse,
+        UpdateTagRequest,
+    },
+    entities::tags,
+    utils::AppError,
+};
+
+pub type DynTagRepository = Arc<dyn TagRepositoryTrait + Send + Sync>;
+pub type DynTagService = Arc<dyn TagServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TagRepositoryTrait {
+    async fn find_all(
+        &self,
+        page: i32,
+        page_size: i32,
+        search: Option<String>,
+    ) -> Result<(Vec<tags::Model>, i64), DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<tags::Model>, DbErr>;
+    async fn find_by_name(&self, name: &str) -> Result<Option<tags::Model>, DbErr>;
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<tags::Model>, DbErr>;
+    /// Returns `(post_id, tag)` pairs for every tag attached to the given posts.
+    async fn find_by_posts(&self, post_ids: &[i32]) -> Result<Vec<(i32, tags::Model)>, DbErr>;
+    /// Returns `(tag_id, count)` of published posts for each of the given tags that has any.
+    async fn count_published_posts(&self, tag_ids: &[i32]) -> Result<Vec<(i32, i64)>, DbErr>;
+    async fn create(&self, name: &str) -> Result<tags::Model, DbErr>;
+    async fn update(&self, id: i32, name: &str) -> Result<Option<tags::Model>, DbErr>;
+    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+}
+
+#[async_trait]
+pub trait TagServiceTrait {
+    async fn get_tags(
+        &self,
+        req: FindAllTagRequest,
+    ) -> Result<ApiResponsePagination<Vec<TagResponse>>, AppError>;
+    async fn get_tag(&self, id: i32) -> Result<ApiResponse<TagResponse>, AppError>;
+    async fn create_tag(
+        &self,
+        input: &CreateTagRequest,
+    ) -> Result<ApiResponse<TagResponse>, AppError>;
+    async fn update_tag(
+        &self,
+        input: &UpdateTagRequest,
+    ) -> Result<ApiResponse<TagResponse>, AppError>;
+    async fn delete_tag(&self, id: i32) -> Result<ApiResponse<()>, AppError>;
+}
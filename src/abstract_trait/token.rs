@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::{prelude::DateTimeWithTimeZone, DbErr};
+
+use crate::entities::refresh_tokens;
+
+pub type DynTokenRepository = Arc<dyn TokenRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TokenRepositoryTrait {
+    async fn create_refresh_token(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        family_id: &str,
+        expires_at: DateTimeWithTimeZone,
+    ) -> Result<refresh_tokens::Model, DbErr>;
+    async fn find_refresh_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<refresh_tokens::Model>, DbErr>;
+    /// Revokes `current` and issues its successor within one transaction.
+    /// Returns `None` when `current` was already revoked by a concurrent request.
+    async fn rotate_refresh_token(
+        &self,
+        current: &refresh_tokens::Model,
+        token_hash: &str,
+        expires_at: DateTimeWithTimeZone,
+    ) -> Result<Option<refresh_tokens::Model>, DbErr>;
+    async fn revoke_refresh_token_family(&self, family_id: &str) -> Result<u64, DbErr>;
+    async fn revoke_access_token(
+        &self,
+        jti: &str,
+        user_id: i32,
+        expires_at: DateTimeWithTimeZone,
+    ) -> Result<(), DbErr>;
+    async fn is_access_token_revoked(&self, jti: &str) -> Result<bool, DbErr>;
+}
@@ -0,0 +1,18 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    domain::{ApiResponse, UploadGcReportResponse, UploadGcStatusResponse},
+    utils::AppError,
+};
+use async_trait::async_trait;
+
+pub type DynUploadGc = Arc<dyn UploadGcTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UploadGcTrait {
+    fn interval(&self) -> Duration;
+    /// Finds stored files and media entries that nothing references any more
+    /// and, unless `dry_run` is set, deletes them.
+    async fn run(&self, dry_run: bool) -> Result<ApiResponse<UploadGcReportResponse>, AppError>;
+    async fn status(&self) -> ApiResponse<UploadGcStatusResponse>;
+}
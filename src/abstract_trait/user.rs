@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use crate::{domain::{ApiResponse, CreateUserRequest, ErrorResponse, UpdateUserRequest, UserResponse}, entities::users};
+use crate::{domain::{ApiResponse, CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest, UserResponse}, entities::users, utils::AppError};
 
 pub type DynUserRepository = Arc<dyn UserRepositoryTrait + Send + Sync>;
 pub type DynUserService = Arc<dyn UserServiceTrait + Send + Sync>;
@@ -21,6 +21,7 @@ pub trait UserRepositoryTrait {
         &self,
         input: &UpdateUserRequest
     ) -> Result<users::Model, DbErr>;
+    async fn update_role(&self, input: &UpdateUserRoleRequest) -> Result<users::Model, DbErr>;
     async fn delete_user(&self, email: &str) -> Result<(), DbErr>;
 }
 
@@ -29,13 +30,17 @@ pub trait UserServiceTrait {
     async fn create_user(
         &self,
         input: &CreateUserRequest
-    ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
-    async fn find_by_email_exists(&self, email: &str) -> Result<ApiResponse<bool>, ErrorResponse>;
-    async fn find_user_by_email(&self, email: &str) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse>;
-    async fn find_by_id(&self, id: i32) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse>;
+    ) -> Result<ApiResponse<UserResponse>, AppError>;
+    async fn find_by_email_exists(&self, email: &str) -> Result<ApiResponse<bool>, AppError>;
+    async fn find_user_by_email(&self, email: &str) -> Result<Option<ApiResponse<UserResponse>>, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<ApiResponse<UserResponse>>, AppError>;
     async fn update_user(
         &self,
         input: &UpdateUserRequest
-    ) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse>;
-    async fn delete_user(&self, email: &str) -> Result<ApiResponse<()>, ErrorResponse>;
+    ) -> Result<Option<ApiResponse<UserResponse>>, AppError>;
+    async fn update_role(
+        &self,
+        input: &UpdateUserRoleRequest
+    ) -> Result<ApiResponse<UserResponse>, AppError>;
+    async fn delete_user(&self, email: &str) -> Result<ApiResponse<()>, AppError>;
 }
\ No newline at end of file
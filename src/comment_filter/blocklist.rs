@@ -0,0 +1,156 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use regex::{Regex, RegexBuilder};
+use tokio::sync::RwLock;
+use tracing::warn;
+
+use crate::{
+    abstract_trait::{CommentFilter, DynCommentFilterRepository, FilterVerdict},
+    domain::CreateCommentRequest,
+    entities::sea_orm_active_enums::{FilterAction, FilterRuleKind},
+    utils::AppError,
+};
+
+/// Compiled programs larger than this are refused, so a rule cannot make
+/// every comment expensive to check.
+const RULE_SIZE_LIMIT: usize = 1 << 20;
+
+/// How long compiled rules are reused before they are read again. Changes made
+/// here take effect at once; this is how soon other instances pick them up.
+const RULES_TTL: Duration = Duration::from_secs(60);
+
+/// Compiles a stored rule. Words and phrases match whole and ignore case;
+/// regular expressions are used as written.
+pub fn compile_rule(kind: FilterRuleKind, pattern: &str) -> Result<Regex, regex::Error> {
+    let pattern = match kind {
+        FilterRuleKind::Word => format!(r"(?i)(?:^|\W){}(?:\W|$)", regex::escape(pattern.trim())),
+        FilterRuleKind::Regex => pattern.to_string(),
+    };
+
+    RegexBuilder::new(&pattern)
+        .size_limit(RULE_SIZE_LIMIT)
+        .build()
+}
+
+struct CompiledRule {
+    id: i32,
+    action: FilterAction,
+    regex: Regex,
+}
+
+struct CachedRules {
+    rules: Arc<Vec<CompiledRule>>,
+    loaded_at: Instant,
+}
+
+/// Matches comments against the blocked words and patterns stored in the
+/// database. The strictest matching rule decides the verdict, and every
+/// matching rule has its hit counter bumped. Rules are compiled once and
+/// reused until they change.
+pub struct BlocklistFilter {
+    repository: DynCommentFilterRepository,
+    cache: RwLock<Option<CachedRules>>,
+    // Bumped on every rule change, so a load that raced with one is not kept.
+    generation: AtomicU64,
+}
+
+impl BlocklistFilter {
+    pub fn new(repository: DynCommentFilterRepository) -> Self {
+        Self {
+            repository,
+            cache: RwLock::new(None),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    async fn rules(&self) -> Result<Arc<Vec<CompiledRule>>, AppError> {
+        if let Some(cached) = self.cache.read().await.as_ref() {
+            if cached.loaded_at.elapsed() < RULES_TTL {
+                return Ok(cached.rules.clone());
+            }
+        }
+
+        let generation = self.generation.load(Ordering::Acquire);
+        let rules: Arc<Vec<CompiledRule>> = Arc::new(
+            self.repository
+                .find_all()
+                .await?
+                .into_iter()
+                .filter_map(|rule| match compile_rule(rule.kind, &rule.pattern) {
+                    Ok(regex) => Some(CompiledRule {
+                        id: rule.id,
+                        action: rule.action,
+                        regex,
+                    }),
+                    Err(e) => {
+                        warn!("Skipping comment filter rule {}: {}", rule.id, e);
+                        None
+                    }
+                })
+                .collect(),
+        );
+
+        let mut cache = self.cache.write().await;
+        if self.generation.load(Ordering::Acquire) == generation {
+            *cache = Some(CachedRules {
+                rules: rules.clone(),
+                loaded_at: Instant::now(),
+            });
+        }
+
+        Ok(rules)
+    }
+}
+
+#[async_trait]
+impl CommentFilter for BlocklistFilter {
+    fn name(&self) -> &'static str {
+        "blocklist"
+    }
+
+    async fn check(
+        &self,
+        _user_id: i32,
+        input: &CreateCommentRequest,
+    ) -> Result<Option<FilterVerdict>, AppError> {
+        let rules = self.rules().await?;
+
+        let mut matched = Vec::new();
+        let mut verdict: Option<FilterVerdict> = None;
+
+        for rule in rules.iter() {
+            if !rule.regex.is_match(&input.comment) {
+                continue;
+            }
+
+            matched.push(rule.id);
+            let stricter = verdict.as_ref().is_none_or(|current| {
+                current.action == FilterAction::Hold && rule.action == FilterAction::Spam
+            });
+            if stricter {
+                verdict = Some(FilterVerdict {
+                    action: rule.action,
+                    reason: format!("matched blocklist rule {}", rule.id),
+                });
+            }
+        }
+
+        if !matched.is_empty() {
+            self.repository.record_hits(&matched).await?;
+        }
+
+        Ok(verdict)
+    }
+
+    async fn rules_changed(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        *self.cache.write().await = None;
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+
+use crate::{
+    abstract_trait::{CommentFilter, DynCommentRepository, FilterVerdict},
+    domain::CreateCommentRequest,
+    entities::sea_orm_active_enums::FilterAction,
+    utils::AppError,
+};
+
+/// Files a comment as spam when its author posted the same text, on any
+/// post, within `window`.
+pub struct DuplicateFilter {
+    repository: DynCommentRepository,
+    window: Duration,
+}
+
+impl DuplicateFilter {
+    pub fn new(repository: DynCommentRepository, window: Duration) -> Self {
+        Self { repository, window }
+    }
+}
+
+#[async_trait]
+impl CommentFilter for DuplicateFilter {
+    fn name(&self) -> &'static str {
+        "duplicate"
+    }
+
+    async fn check(
+        &self,
+        user_id: i32,
+        input: &CreateCommentRequest,
+    ) -> Result<Option<FilterVerdict>, AppError> {
+        let since = Utc::now() - TimeDelta::from_std(self.window).unwrap_or(TimeDelta::MAX);
+
+        let duplicate = self
+            .repository
+            .exists_recent_by_user(user_id, &input.comment, since.fixed_offset())
+            .await?;
+
+        Ok(duplicate.then(|| FilterVerdict {
+            action: FilterAction::Spam,
+            reason: format!(
+                "same comment posted within the last {} seconds",
+                self.window.as_secs()
+            ),
+        }))
+    }
+}
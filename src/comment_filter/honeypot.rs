@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::{
+    abstract_trait::{CommentFilter, FilterVerdict},
+    domain::CreateCommentRequest,
+    entities::sea_orm_active_enums::FilterAction,
+    utils::AppError,
+};
+
+/// Files comments as spam when the hidden `website` field was filled in.
+pub struct HoneypotFilter;
+
+#[async_trait]
+impl CommentFilter for HoneypotFilter {
+    fn name(&self) -> &'static str {
+        "honeypot"
+    }
+
+    async fn check(
+        &self,
+        _user_id: i32,
+        input: &CreateCommentRequest,
+    ) -> Result<Option<FilterVerdict>, AppError> {
+        let filled = input
+            .website
+            .as_deref()
+            .is_some_and(|website| !website.trim().is_empty());
+
+        Ok(filled.then(|| FilterVerdict {
+            action: FilterAction::Spam,
+            reason: "honeypot field was filled in".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::LazyLock;
+
+use async_trait::async_trait;
+use regex::Regex;
+
+use crate::{
+    abstract_trait::{CommentFilter, FilterVerdict},
+    domain::CreateCommentRequest,
+    entities::sea_orm_active_enums::FilterAction,
+    utils::AppError,
+};
+
+static LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?:https?://|www\.)").expect("valid link pattern"));
+
+/// Holds comments carrying more links than `max_links`.
+pub struct LinkLimitFilter {
+    max_links: usize,
+}
+
+impl LinkLimitFilter {
+    pub fn new(max_links: usize) -> Self {
+        Self { max_links }
+    }
+}
+
+#[async_trait]
+impl CommentFilter for LinkLimitFilter {
+    fn name(&self) -> &'static str {
+        "link_limit"
+    }
+
+    async fn check(
+        &self,
+        _user_id: i32,
+        input: &CreateCommentRequest,
+    ) -> Result<Option<FilterVerdict>, AppError> {
+        let links = LINK.find_iter(&input.comment).count();
+
+        Ok((links > self.max_links).then(|| FilterVerdict {
+            action: FilterAction::Hold,
+            reason: format!("{} links, at most {} allowed", links, self.max_links),
+        }))
+    }
+}
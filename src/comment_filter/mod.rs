@@ -0,0 +1,12 @@
+//! Built-in [`CommentFilter`](crate::abstract_trait::CommentFilter)s run on
+//! every new comment.
+
+mod blocklist;
+mod duplicate;
+mod honeypot;
+mod link_limit;
+
+pub use self::blocklist::{compile_rule, BlocklistFilter};
+pub use self::duplicate::DuplicateFilter;
+pub use self::honeypot::HoneypotFilter;
+pub use self::link_limit::LinkLimitFilter;
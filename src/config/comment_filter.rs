@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+/// Limits applied by the built-in comment filters.
+#[derive(Debug, Clone)]
+pub struct CommentFilterConfig {
+    /// Comments with more links than this are held for moderation.
+    pub max_links: usize,
+    /// How far back an author's identical comments count as duplicates; zero
+    /// disables duplicate detection.
+    pub duplicate_window: Duration,
+}
+
+impl CommentFilterConfig {
+    /// Reads `COMMENT_MAX_LINKS` (2 by default) and `COMMENT_DUPLICATE_WINDOW_SECS` (an hour).
+    pub fn init() -> CommentFilterConfig {
+        let max_links = std::env::var("COMMENT_MAX_LINKS")
+            .map(|links| links.parse().expect("Invalid value for COMMENT_MAX_LINKS"))
+            .unwrap_or(2);
+        let duplicate_window = std::env::var("COMMENT_DUPLICATE_WINDOW_SECS")
+            .map(|secs| {
+                secs.parse()
+                    .expect("Invalid value for COMMENT_DUPLICATE_WINDOW_SECS")
+            })
+            .unwrap_or(3600);
+
+        CommentFilterConfig {
+            max_links,
+            duplicate_window: Duration::from_secs(duplicate_window),
+        }
+    }
+}
@@ -1,9 +1,22 @@
+use super::{CommentFilterConfig, StorageConfig, UploadGcConfig};
+use crate::utils::VariantSettings;
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database_url: String,
     pub jwt_secret: String,
     pub run_migrations: bool,
     pub port: u16,
+    /// Public prefix of the `/media` route, used to build absolute image URLs.
+    pub media_base_url: String,
+    pub storage: StorageConfig,
+    pub image_variants: VariantSettings,
+    pub upload_gc: UploadGcConfig,
+    /// How deeply comment replies may nest; 0 disables replies.
+    pub comment_max_depth: i32,
+    /// Hold every new comment for moderation, not just first-time commenters'.
+    pub comments_require_approval: bool,
+    pub comment_filter: CommentFilterConfig,
 }
 
 impl Config {
@@ -24,7 +37,40 @@ impl Config {
 
         let port = port_str.parse().expect("Invalid value for PORT");
 
-        Config { database_url, jwt_secret, run_migrations, port }
+        let media_base_url = std::env::var("MEDIA_BASE_URL")
+            .unwrap_or_else(|_| format!("http://localhost:{}/media", port));
+
+        let storage = StorageConfig::init();
+
+        let defaults = VariantSettings::default();
+        let image_variants = VariantSettings {
+            widths: std::env::var("IMAGE_VARIANT_WIDTHS")
+                .map(|widths| {
+                    widths
+                        .split(',')
+                        .filter(|width| !width.trim().is_empty())
+                        .map(|width| width.trim().parse().expect("Invalid value for IMAGE_VARIANT_WIDTHS"))
+                        .collect()
+                })
+                .unwrap_or(defaults.widths),
+            webp: std::env::var("IMAGE_VARIANT_WEBP")
+                .map(|webp| webp != "false")
+                .unwrap_or(defaults.webp),
+        };
+
+        let upload_gc = UploadGcConfig::init();
+
+        let comment_max_depth = std::env::var("COMMENT_MAX_DEPTH")
+            .map(|depth| depth.parse().expect("Invalid value for COMMENT_MAX_DEPTH"))
+            .unwrap_or(5);
+
+        let comments_require_approval = std::env::var("COMMENTS_REQUIRE_APPROVAL")
+            .map(|require| require == "true")
+            .unwrap_or(false);
+
+        let comment_filter = CommentFilterConfig::init();
+
+        Config { database_url, jwt_secret, run_migrations, port, media_base_url, storage, image_variants, upload_gc, comment_max_depth, comments_require_approval, comment_filter }
  
     }
 }
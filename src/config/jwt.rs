@@ -3,62 +3,74 @@ use jsonwebtoken::{
     decode, encode, errors::ErrorKind as JwtError, DecodingKey, EncodingKey, Header, Validation,
 };
 use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
 
-use crate::utils::AppError;
+use crate::{entities::sea_orm_active_enums::Role, utils::AppError};
 
 
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub user_id: i64,
+    pub role: Role,
+    pub jti: String,
     pub exp: usize,
     pub iat: usize,
 }
 
 impl Claims {
-    pub fn new(user_id: i64, exp: usize, iat: usize) -> Self {
-        Claims { user_id, exp, iat}
+    pub fn new(user_id: i64, role: Role, jti: String, exp: usize, iat: usize) -> Self {
+        Claims { user_id, role, jti, exp, iat }
+    }
+
+    pub fn is_owner_or_admin(&self, owner_id: i32) -> bool {
+        self.user_id == owner_id as i64 || self.role == Role::Admin
     }
 }
 
 #[derive(Clone)]
 pub struct JwtConfig{
     pub jwt_secret: String,
+    pub access_token_ttl: Duration,
+    pub refresh_token_ttl: Duration,
 }
 
 impl JwtConfig {
     pub fn new(jwt_secret: &str) -> Self {
         JwtConfig{
             jwt_secret: jwt_secret.to_string(),
+            access_token_ttl: Duration::minutes(15),
+            refresh_token_ttl: Duration::days(30),
         }
     }
 
-    pub fn generate_token(&self, user_id: i64) -> Result<String, AppError> {
+    pub fn generate_token(&self, user_id: i64, role: Role) -> Result<String, AppError> {
         let now = Utc::now();
         let iat = now.timestamp() as usize;
-        let exp = (now + Duration::minutes(60)).timestamp() as usize;
+        let exp = (now + self.access_token_ttl).timestamp() as usize;
 
-        let claims = Claims::new(user_id, exp, iat);
+        let claims = Claims::new(user_id, role, Uuid::new_v4().to_string(), exp, iat);
 
         match encode(
             &Header::default(),
             &claims,
-            &EncodingKey::from_secret("YOUR_SECRET_KEY".as_ref()),
+            &EncodingKey::from_secret(self.jwt_secret.as_ref()),
         ) {
             Ok(token) => Ok(token),
-            Err(err) => Err(AppError::TokenGenerationError(err.into())),
+            Err(err) => Err(AppError::TokenGenerationError(err)),
         }
     }
 
-    pub fn verify_token(&self, token: &str) -> Result<i64, AppError> {
-        let decoding_key = DecodingKey::from_secret("YOUR_SECRET_KEY".as_ref());
-    
+    pub fn decode_token(&self, token: &str) -> Result<Claims, AppError> {
+        let decoding_key = DecodingKey::from_secret(self.jwt_secret.as_ref());
+
         match decode::<Claims>(token, &decoding_key, &Validation::default()) {
             Ok(token_data) => {
                 let current_time = Utc::now().timestamp() as usize;
-    
+
                 if token_data.claims.exp >= current_time {
-                    Ok(token_data.claims.user_id)
+                    Ok(token_data.claims)
                 } else {
                     Err(AppError::TokenExpiredError)
                 }
@@ -73,5 +85,17 @@ impl JwtConfig {
             }
         }
     }
-    
-}
\ No newline at end of file
+
+    pub fn verify_token(&self, token: &str) -> Result<i64, AppError> {
+        self.decode_token(token).map(|claims| claims.user_id)
+    }
+
+    // Refresh tokens are opaque random strings; only their SHA-256 digest is stored.
+    pub fn generate_refresh_token(&self) -> String {
+        format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+    }
+
+    pub fn hash_refresh_token(&self, token: &str) -> String {
+        format!("{:x}", Sha256::digest(token.as_bytes()))
+    }
+}
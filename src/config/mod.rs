@@ -1,9 +1,16 @@
 mod hashing;
 mod jwt;
+#[allow(clippy::module_inception)]
 mod config;
+mod comment_filter;
 mod database;
+mod storage;
+mod upload_gc;
 
-pub use self::jwt::JwtConfig;
+pub use self::jwt::{Claims, JwtConfig};
 pub use self::hashing::Hashing;
 pub use self::config::Config;
-pub use self::database::ConnectionManager;
\ No newline at end of file
+pub use self::comment_filter::CommentFilterConfig;
+pub use self::database::ConnectionManager;
+pub use self::storage::StorageConfig;
+pub use self::upload_gc::UploadGcConfig;
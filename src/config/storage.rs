@@ -0,0 +1,57 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    abstract_trait::DynStorageBackend,
+    storage::{LocalStorage, S3Settings, S3Storage},
+    utils::AppError,
+};
+
+#[derive(Debug, Clone)]
+pub enum StorageConfig {
+    Local { root: String },
+    S3(S3Settings),
+}
+
+impl StorageConfig {
+    /// Reads `STORAGE_BACKEND` (`local` by default) and the settings of the chosen backend.
+    pub fn init() -> StorageConfig {
+        let backend = std::env::var("STORAGE_BACKEND").unwrap_or_else(|_| "local".to_string());
+
+        match backend.as_str() {
+            "local" => StorageConfig::Local {
+                root: std::env::var("STORAGE_LOCAL_ROOT").unwrap_or_else(|_| "posts".to_string()),
+            },
+            "s3" => {
+                let url_ttl = std::env::var("S3_URL_TTL_SECS")
+                    .map(|ttl| ttl.parse().expect("Invalid value for S3_URL_TTL_SECS"))
+                    .unwrap_or(3600);
+
+                StorageConfig::S3(S3Settings {
+                    bucket: std::env::var("S3_BUCKET").expect("S3_BUCKET must be set"),
+                    region: std::env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+                    endpoint: std::env::var("S3_ENDPOINT").ok(),
+                    access_key_id: std::env::var("S3_ACCESS_KEY_ID")
+                        .expect("S3_ACCESS_KEY_ID must be set"),
+                    secret_access_key: std::env::var("S3_SECRET_ACCESS_KEY")
+                        .expect("S3_SECRET_ACCESS_KEY must be set"),
+                    url_ttl: Duration::from_secs(url_ttl),
+                    key_prefix: std::env::var("S3_KEY_PREFIX")
+                        .unwrap_or_else(|_| "uploads".to_string()),
+                })
+            }
+            _ => panic!("STORAGE_BACKEND must be either 'local' or 's3'"),
+        }
+    }
+
+    /// Builds the configured backend; local files are linked through `media_base_url`.
+    pub fn backend(&self, media_base_url: &str) -> Result<DynStorageBackend, AppError> {
+        let backend = match self {
+            StorageConfig::Local { root } => {
+                Arc::new(LocalStorage::new(root, media_base_url)) as DynStorageBackend
+            }
+            StorageConfig::S3(settings) => Arc::new(S3Storage::new(settings)?) as DynStorageBackend,
+        };
+
+        Ok(backend)
+    }
+}
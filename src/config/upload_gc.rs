@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// Schedule of the sweeper that removes uploads nothing refers to.
+#[derive(Debug, Clone)]
+pub struct UploadGcConfig {
+    /// Time between background sweeps; zero disables the background sweeper.
+    pub interval: Duration,
+    /// Files and media entries younger than this are never collected, so
+    /// uploads whose post is still being saved survive.
+    pub grace_period: Duration,
+}
+
+impl UploadGcConfig {
+    /// Reads `UPLOAD_GC_INTERVAL_SECS` (hourly by default) and `UPLOAD_GC_GRACE_SECS` (a day).
+    pub fn init() -> UploadGcConfig {
+        let interval = std::env::var("UPLOAD_GC_INTERVAL_SECS")
+            .map(|secs| {
+                secs.parse()
+                    .expect("Invalid value for UPLOAD_GC_INTERVAL_SECS")
+            })
+            .unwrap_or(3600);
+        let grace_period = std::env::var("UPLOAD_GC_GRACE_SECS")
+            .map(|secs| {
+                secs.parse()
+                    .expect("Invalid value for UPLOAD_GC_GRACE_SECS")
+            })
+            .unwrap_or(86400);
+
+        UploadGcConfig {
+            interval: Duration::from_secs(interval),
+            grace_period: Duration::from_secs(grace_period),
+        }
+    }
+}
@@ -2,12 +2,23 @@ mod request;
 mod response;
 
 pub use self::request::{
-    CreateCategoryRequest, CreateCommentRequest, CreatePostRequest, CreateUserRequest,
-    FindAllCategoryRequest, FindAllPostRequest, LoginRequest, RegisterRequest,
-    UpdateCategoryRequest, UpdateCommentRequest, UpdatePostRequest, UpdateUserRequest,
+    CommentSort, CreateCategoryRequest, CreateCommentFilterRuleRequest, CreateCommentRequest,
+    CreateMediaRequest, CreatePostRequest, CreateTagRequest, CreateUserRequest,
+    DeleteCategoryRequest, FindAllCategoryRequest, FindAllMediaRequest, FindAllPostRequest,
+    FindAllTagRequest, FindModerationQueueRequest, FindPostCommentsRequest, LoginRequest,
+    LogoutRequest, MergeCategoryRequest, ModerateCommentsRequest, PostFilter, PostForm,
+    PostTransition, PostVisibility, RefreshTokenRequest, RegisterRequest, RevisionDiffRequest,
+    RunUploadGcRequest, SchedulePostRequest, SearchMode, TagMatch, UpdateCategoryRequest,
+    UpdateCommentFilterRuleRequest, UpdateCommentRequest, UpdateCommentSettingsRequest,
+    UpdatePostRequest, UpdateTagRequest, UpdateUserRequest, UpdateUserRoleRequest, UploadedFile,
 };
 
 pub use self::response::{
-    ApiResponse, ApiResponsePagination, CategoryResponse, CommentResponse, DeleteResponse,
-    ErrorResponse, Pagination, PostRelationResponse, PostResponse, UploadResponse, UserResponse,
+    ApiResponse, ApiResponsePagination, CategoryBreadcrumb, CategoryMergeResponse, CategoryResponse,
+    CategoryTreeResponse, CommentFilterRuleResponse, CommentFilterStatsResponse, CommentResponse,
+    CommentSettingsResponse, CommentTreeResponse, DeleteResponse, DiffLineResponse, DiffOp,
+    MediaResponse, OrphanedFileResponse, Pagination, PostRelationResponse, PostResponse,
+    PostRevisionResponse, PostSlugLookup, FieldError, ProblemDetails, RevisionDiffResponse,
+    SchedulerStatusResponse, TagResponse, TokenResponse, UploadGcReportResponse,
+    UploadGcStatusResponse, UploadResponse, UserResponse,
 };
@@ -1,17 +1,45 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use validator::Validate;
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
 pub struct RegisterRequest {
+    #[validate(length(min = 1, max = 100, message = "must be between 1 and 100 characters"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub firstname: String,
+    #[validate(length(min = 1, max = 100, message = "must be between 1 and 100 characters"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub lastname: String,
+    #[validate(email(message = "must be a valid email address"))]
+    #[schema(format = Email)]
     pub email: String,
+    // bcrypt ignores everything past 72 bytes
+    #[validate(length(min = 8, max = 72, message = "must be between 8 and 72 characters"))]
+    #[schema(format = Password, min_length = 8, max_length = 72)]
     pub password: String,
 }
 
 
-#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema, Validate)]
 pub struct LoginRequest {
+    #[validate(email(message = "must be a valid email address"))]
+    #[schema(format = Email)]
     pub email: String,
+    #[validate(length(min = 1, message = "must not be empty"))]
+    #[schema(format = Password, min_length = 1)]
     pub password: String,
 }
+
+#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema, Validate)]
+pub struct RefreshTokenRequest {
+    #[validate(length(min = 1, message = "must not be empty"))]
+    #[schema(min_length = 1)]
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema, Validate)]
+pub struct LogoutRequest {
+    #[validate(length(min = 1, message = "must not be empty"))]
+    #[schema(min_length = 1)]
+    pub refresh_token: Option<String>,
+}
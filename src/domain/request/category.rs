@@ -1,15 +1,24 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
 
-#[derive(Serialize, Deserialize, Clone, Debug, IntoParams)]
+use crate::utils::generate_slug;
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
 pub struct FindAllCategoryRequest {
     #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "must be at least 1"))]
+    #[param(minimum = 1)]
     pub page: i32,
 
     #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = 100, message = "must be between 1 and 100"))]
+    #[param(minimum = 1, maximum = 100)]
     pub page_size: i32,
 
     #[serde(default)]
+    #[validate(length(max = 200, message = "must be at most 200 characters"))]
+    #[param(max_length = 200)]
     pub search: String,
 }
 
@@ -21,13 +30,97 @@ fn default_page_size() -> i32 {
     10
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+/// Accepts only slugs `generate_slug` leaves unchanged, which is what category
+/// slugs generated from names look like.
+fn validate_slug(slug: &str) -> Result<(), ValidationError> {
+    if generate_slug(slug) == slug {
+        return Ok(());
+    }
+
+    Err(ValidationError::new("slug")
+        .with_message("must contain only lowercase letters, digits and single hyphens".into()))
+}
+
+/// Tells an omitted field (`None`) apart from an explicit `null` (`Some(None)`).
+fn double_option<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
 pub struct CreateCategoryRequest {
+    #[validate(length(min = 1, max = 100, message = "must be between 1 and 100 characters"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub name: String,
+    /// Lowercase letters, digits and hyphens; generated from the name when omitted
+    #[validate(
+        length(min = 1, max = 100, message = "must be between 1 and 100 characters"),
+        custom(function = "validate_slug")
+    )]
+    #[schema(
+        min_length = 1,
+        max_length = 100,
+        pattern = r"^[a-z0-9]+(?:-[a-z0-9]+)*$"
+    )]
+    pub slug: Option<String>,
+    #[validate(length(max = 1000, message = "must be at most 1000 characters"))]
+    #[schema(max_length = 1000)]
+    pub description: Option<String>,
+    /// Position among its siblings; lower values come first
+    #[serde(default)]
+    pub sort_order: i32,
+    /// Category to nest this one under; omit for a top-level category
+    #[validate(range(min = 1, message = "must be a valid category id"))]
+    #[schema(minimum = 1)]
+    pub parent_id: Option<i32>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
 pub struct UpdateCategoryRequest {
     pub id: Option<i32>,
+    #[validate(length(min = 1, max = 100, message = "must be between 1 and 100 characters"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub name: Option<String>,
+    /// New slug; renaming a category keeps its current slug unless this is given
+    #[validate(
+        length(min = 1, max = 100, message = "must be between 1 and 100 characters"),
+        custom(function = "validate_slug")
+    )]
+    #[schema(
+        min_length = 1,
+        max_length = 100,
+        pattern = r"^[a-z0-9]+(?:-[a-z0-9]+)*$"
+    )]
+    pub slug: Option<String>,
+    /// New description; `null` clears it, omitted keeps the current one
+    #[serde(default, deserialize_with = "double_option")]
+    #[validate(length(max = 1000, message = "must be at most 1000 characters"))]
+    #[schema(value_type = Option<String>, max_length = 1000)]
+    pub description: Option<Option<String>>,
+    pub sort_order: Option<i32>,
+    /// New parent category; `null` makes it top-level, omitted keeps the current parent
+    #[serde(default, deserialize_with = "double_option")]
+    #[schema(value_type = Option<i32>, minimum = 1)]
+    pub parent_id: Option<Option<i32>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
+#[into_params(parameter_in = Query)]
+pub struct DeleteCategoryRequest {
+    /// Category that receives the posts and subcategories of the deleted one;
+    /// required while the category still holds posts
+    #[validate(range(min = 1, message = "must be a valid category id"))]
+    #[param(minimum = 1)]
+    pub reassign_to: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
+pub struct MergeCategoryRequest {
+    /// Category that receives the posts and subcategories of the merged one
+    #[validate(range(min = 1, message = "must be a valid category id"))]
+    #[schema(minimum = 1)]
+    pub target_id: i32,
 }
@@ -1,16 +1,112 @@
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
 
-#[derive(Debug, Deserialize, Serialize, ToSchema)]
+use crate::entities::sea_orm_active_enums::CommentStatus;
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
+#[into_params(parameter_in = Query)]
+pub struct FindPostCommentsRequest {
+    /// `next_cursor` of the previous page; omit for the first page
+    #[validate(range(min = 1, message = "must be a valid cursor"))]
+    #[param(minimum = 1)]
+    pub cursor: Option<i32>,
+
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = 100, message = "must be between 1 and 100"))]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i32,
+
+    #[serde(default)]
+    #[param(inline)]
+    pub sort: CommentSort,
+
+    /// Only comments written by this user
+    #[validate(range(min = 1, message = "must be a valid user id"))]
+    #[param(minimum = 1)]
+    pub user_id: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentSort {
+    #[default]
+    Newest,
+    Oldest,
+}
+
+fn default_page_size() -> i32 {
+    20
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
+#[into_params(parameter_in = Query)]
+pub struct FindModerationQueueRequest {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "must be at least 1"))]
+    #[param(minimum = 1)]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = 100, message = "must be between 1 and 100"))]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i32,
+
+    #[serde(default = "default_moderation_status")]
+    #[param(inline)]
+    pub status: CommentStatus,
+
+    /// Only comments on this post
+    #[validate(range(min = 1, message = "must be a valid post id"))]
+    #[param(minimum = 1)]
+    pub post_id: Option<i32>,
+}
+
+fn default_page() -> i32 {
+    1
+}
+
+fn default_moderation_status() -> CommentStatus {
+    CommentStatus::Pending
+}
+
+/// Moves every listed comment to `status` at once.
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct ModerateCommentsRequest {
+    #[validate(length(min = 1, max = 100, message = "must list between 1 and 100 comments"))]
+    #[schema(min_items = 1, max_items = 100)]
+    pub ids: Vec<i32>,
+    pub status: CommentStatus,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct UpdateCommentSettingsRequest {
+    /// Hold every new comment on the post for moderation
+    pub require_approval: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct CreateCommentRequest {
+    #[validate(range(min = 1, message = "must be a valid post id"))]
+    #[schema(minimum = 1)]
     pub id_post_comment: i32,
-    pub user_name_comment: String,
+    #[validate(length(min = 1, max = 5000, message = "must be between 1 and 5000 characters"))]
+    #[schema(min_length = 1, max_length = 5000)]
     pub comment: String,
+    /// Comment being replied to; it must belong to the same post
+    #[validate(range(min = 1, message = "must be a valid comment id"))]
+    #[schema(minimum = 1)]
+    pub parent_id: Option<i32>,
+    /// Honeypot: comment forms hide this field from people, so anything in it
+    /// marks the comment as spam
+    #[serde(default)]
+    pub website: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct UpdateCommentRequest {
-    pub id_post_comment: i32,
-    pub user_name_comment: String,
+    pub id: Option<i32>,
+    #[validate(length(min = 1, max = 5000, message = "must be between 1 and 5000 characters"))]
+    #[schema(min_length = 1, max_length = 5000)]
     pub comment: String,
-}
\ No newline at end of file
+}
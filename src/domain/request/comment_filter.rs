@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::{FilterAction, FilterRuleKind};
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
+pub struct CreateCommentFilterRuleRequest {
+    pub kind: FilterRuleKind,
+    /// A word or phrase for `word` rules, a regular expression for `regex` rules
+    #[validate(length(min = 1, max = 500, message = "must be between 1 and 500 characters"))]
+    #[schema(min_length = 1, max_length = 500)]
+    pub pattern: String,
+    pub action: FilterAction,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
+pub struct UpdateCommentFilterRuleRequest {
+    pub id: Option<i32>,
+    pub kind: FilterRuleKind,
+    #[validate(length(min = 1, max = 500, message = "must be between 1 and 500 characters"))]
+    #[schema(min_length = 1, max_length = 500)]
+    pub pattern: String,
+    pub action: FilterAction,
+}
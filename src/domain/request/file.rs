@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+/// A file field from a multipart form, spooled to a temporary file while the
+/// request is read. The temporary file is removed when this value is dropped
+/// unless storage has already moved it into place.
+#[derive(Debug)]
+pub struct UploadedFile {
+    pub file_name: String,
+    pub content_type: String,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+impl Drop for UploadedFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
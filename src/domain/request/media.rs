@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
+pub struct FindAllMediaRequest {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "must be at least 1"))]
+    #[param(minimum = 1)]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = 100, message = "must be between 1 and 100"))]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i32,
+
+    /// Matches the original file name
+    #[serde(default)]
+    #[validate(length(max = 200, message = "must be at most 200 characters"))]
+    #[param(max_length = 200)]
+    pub search: String,
+
+    /// Exact MIME type, e.g. `image/png`
+    #[serde(default)]
+    #[validate(length(max = 100, message = "must be at most 100 characters"))]
+    #[param(max_length = 100)]
+    pub mime: Option<String>,
+}
+
+fn default_page() -> i32 {
+    1
+}
+
+fn default_page_size() -> i32 {
+    10
+}
+
+/// A stored upload about to be recorded in the media library.
+#[derive(Debug, Clone)]
+pub struct CreateMediaRequest {
+    pub hash: String,
+    pub path: String,
+    pub file_name: String,
+    pub mime: String,
+    pub size: i64,
+    pub width: i32,
+    pub height: i32,
+    pub user_id: i32,
+}
@@ -1,14 +1,38 @@
 mod auth;
 mod category;
 mod comment;
+mod comment_filter;
+mod file;
+mod media;
 mod post;
+mod tag;
+mod upload_gc;
 mod user;
 
-pub use self::category::{CreateCategoryRequest, FindAllCategoryRequest, UpdateCategoryRequest};
-pub use self::post::{CreatePostRequest, FindAllPostRequest, UpdatePostRequest};
+pub use self::category::{
+    CreateCategoryRequest, DeleteCategoryRequest, FindAllCategoryRequest, MergeCategoryRequest,
+    UpdateCategoryRequest,
+};
+pub use self::post::{
+    CreatePostRequest, FindAllPostRequest, PostFilter, PostForm, PostTransition, PostVisibility,
+    RevisionDiffRequest, SchedulePostRequest, SearchMode, TagMatch, UpdatePostRequest,
+};
 
-pub use self::comment::{CreateCommentRequest, UpdateCommentRequest};
+pub use self::file::UploadedFile;
 
-pub use self::auth::{LoginRequest, RegisterRequest};
+pub use self::media::{CreateMediaRequest, FindAllMediaRequest};
 
-pub use self::user::{CreateUserRequest, UpdateUserRequest};
+pub use self::tag::{CreateTagRequest, FindAllTagRequest, UpdateTagRequest};
+
+pub use self::upload_gc::RunUploadGcRequest;
+
+pub use self::comment::{
+    CommentSort, CreateCommentRequest, FindModerationQueueRequest, FindPostCommentsRequest,
+    ModerateCommentsRequest, UpdateCommentRequest, UpdateCommentSettingsRequest,
+};
+
+pub use self::comment_filter::{CreateCommentFilterRuleRequest, UpdateCommentFilterRuleRequest};
+
+pub use self::auth::{LoginRequest, LogoutRequest, RefreshTokenRequest, RegisterRequest};
+
+pub use self::user::{CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest};
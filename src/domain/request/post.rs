@@ -1,16 +1,68 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
 
-#[derive(Serialize, Deserialize, Clone, Debug, IntoParams)]
+use super::UploadedFile;
+use crate::entities::sea_orm_active_enums::PostStatus;
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
 pub struct FindAllPostRequest {
     #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "must be at least 1"))]
+    #[param(minimum = 1)]
     pub page: i32,
 
     #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = 100, message = "must be between 1 and 100"))]
+    #[param(minimum = 1, maximum = 100)]
     pub page_size: i32,
 
     #[serde(default)]
+    #[validate(length(max = 200, message = "must be at most 200 characters"))]
+    #[param(max_length = 200)]
     pub search: String,
+
+    #[serde(default)]
+    pub search_mode: SearchMode,
+
+    #[serde(default)]
+    pub status: Option<PostStatus>,
+
+    /// Comma-separated tag names
+    #[serde(default)]
+    pub tags: String,
+
+    #[serde(default)]
+    pub tag_match: TagMatch,
+
+    /// Only posts in this category or any category below it
+    #[validate(range(min = 1, message = "must be a valid category id"))]
+    #[param(minimum = 1)]
+    pub category_id: Option<i32>,
+}
+
+/// How the `search` parameter is matched against posts.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+    /// Case-insensitive substring match on the title
+    #[default]
+    Basic,
+    /// Ranked full-text search over title and body; supports `"phrases"`, `-negation`,
+    /// `prefix*` and `or`
+    FullText,
+}
+
+/// How multiple tags in a post filter are combined.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatch {
+    /// Posts carrying at least one of the tags
+    #[default]
+    Any,
+    /// Posts carrying every one of the tags
+    All,
 }
 
 fn default_page() -> i32 {
@@ -21,25 +73,137 @@ fn default_page_size() -> i32 {
     10
 }
 
-#[derive(Debug, Deserialize, Serialize, ToSchema)]
+/// Which posts a caller may see when listing.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum PostVisibility {
+    /// Anonymous callers only see published posts.
+    #[default]
+    Public,
+    /// Published posts plus everything written by this user.
+    Author(i32),
+    /// Reviewers see posts in every status.
+    Staff,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PostFilter {
+    pub search: Option<String>,
+    pub status: Option<PostStatus>,
+    pub tags: Vec<String>,
+    pub tag_match: TagMatch,
+    /// Root of the category subtree posts must belong to
+    pub category_id: Option<i32>,
+    pub visibility: PostVisibility,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostTransition {
+    Submit,
+    Publish,
+    Reject,
+    Archive,
+    Restore,
+}
+
+impl PostTransition {
+    pub fn allowed_from(&self) -> &'static [PostStatus] {
+        match self {
+            PostTransition::Submit => &[PostStatus::Draft],
+            PostTransition::Publish => &[
+                PostStatus::Draft,
+                PostStatus::InReview,
+                PostStatus::Scheduled,
+            ],
+            PostTransition::Reject => &[PostStatus::InReview, PostStatus::Scheduled],
+            PostTransition::Archive => &[PostStatus::Published],
+            PostTransition::Restore => &[PostStatus::Archived],
+        }
+    }
+
+    pub fn target(&self) -> PostStatus {
+        match self {
+            PostTransition::Submit => PostStatus::InReview,
+            PostTransition::Publish => PostStatus::Published,
+            PostTransition::Reject | PostTransition::Restore => PostStatus::Draft,
+            PostTransition::Archive => PostStatus::Archived,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct SchedulePostRequest {
+    #[schema(value_type = String, format = DateTime)]
+    pub publish_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct CreatePostRequest {
+    #[validate(length(min = 1, max = 255, message = "must be between 1 and 255 characters"))]
+    #[schema(min_length = 1, max_length = 255)]
     pub title: String,
+    #[validate(length(min = 1, message = "must not be empty"))]
+    #[schema(min_length = 1)]
     pub body: String,
     #[schema(format = Binary, content_media_type = "application/octet-stream")]
     pub file: String,
+    /// Media library entry of `file`, filled in once the upload is stored
+    #[serde(skip)]
+    #[schema(ignore)]
+    pub media_id: Option<i32>,
+    #[validate(range(min = 1, message = "must be a valid category id"))]
+    #[schema(minimum = 1)]
     pub category_id: i32,
-    pub user_id: i32,
-    pub user_name: String,
+    #[serde(default)]
+    pub tag_ids: Vec<i32>,
 }
 
-#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct UpdatePostRequest {
     pub post_id: Option<i32>,
+    #[validate(length(min = 1, max = 255, message = "must be between 1 and 255 characters"))]
+    #[schema(min_length = 1, max_length = 255)]
     pub title: String,
+    #[validate(length(min = 1, message = "must not be empty"))]
+    #[schema(min_length = 1)]
     pub body: String,
+    /// Replaces the post's image when present; omitted keeps the current image
     #[schema(format = Binary, content_media_type = "application/octet-stream")]
-    pub file: String,
+    pub file: Option<String>,
+    /// Media library entry of `file`, filled in once the upload is stored
+    #[serde(skip)]
+    #[schema(ignore)]
+    pub media_id: Option<i32>,
+    #[validate(range(min = 1, message = "must be a valid category id"))]
+    #[schema(minimum = 1)]
     pub category_id: i32,
-    pub user_id: i32,
-    pub user_name: String,
+    /// Replaces the post's tags when present; omitted keeps the current tags
+    #[serde(default)]
+    pub tag_ids: Option<Vec<i32>>,
+}
+
+/// Fields of the `multipart/form-data` body shared by post create and update.
+#[derive(Debug, Default, Validate)]
+pub struct PostForm {
+    #[validate(length(min = 1, max = 255, message = "must be between 1 and 255 characters"))]
+    pub title: String,
+    #[validate(length(min = 1, message = "must not be empty"))]
+    pub body: String,
+    #[validate(range(min = 1, message = "must be a valid category id"))]
+    pub category_id: i32,
+    /// `None` when the form carried no `tag_ids` field at all
+    pub tag_ids: Option<Vec<i32>>,
+    /// `None` when no file was attached; updates then keep the current image
+    pub file: Option<UploadedFile>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, Validate)]
+pub struct RevisionDiffRequest {
+    /// Revision number to diff from
+    #[validate(range(min = 1, message = "must be at least 1"))]
+    #[param(minimum = 1)]
+    pub from: i32,
+    /// Revision number to diff to
+    #[validate(range(min = 1, message = "must be at least 1"))]
+    #[param(minimum = 1)]
+    pub to: i32,
 }
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
+pub struct FindAllTagRequest {
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "must be at least 1"))]
+    #[param(minimum = 1)]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = 100, message = "must be between 1 and 100"))]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i32,
+
+    #[serde(default)]
+    #[validate(length(max = 200, message = "must be at most 200 characters"))]
+    #[param(max_length = 200)]
+    pub search: String,
+}
+
+fn default_page() -> i32 {
+    1
+}
+
+fn default_page_size() -> i32 {
+    10
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
+pub struct CreateTagRequest {
+    #[validate(length(min = 1, max = 64, message = "must be between 1 and 64 characters"))]
+    #[schema(min_length = 1, max_length = 64)]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
+pub struct UpdateTagRequest {
+    pub id: Option<i32>,
+    #[validate(length(min = 1, max = 64, message = "must be between 1 and 64 characters"))]
+    #[schema(min_length = 1, max_length = 64)]
+    pub name: String,
+}
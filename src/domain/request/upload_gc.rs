@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
+pub struct RunUploadGcRequest {
+    /// Only report what would be removed; pass `false` to delete
+    #[serde(default = "default_dry_run")]
+    #[param(default = true)]
+    pub dry_run: bool,
+}
+
+fn default_dry_run() -> bool {
+    true
+}
@@ -1,21 +1,47 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use validator::Validate;
 
-#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema)]
+use crate::entities::sea_orm_active_enums::Role;
+
+#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema, Validate)]
 pub struct CreateUserRequest {
+    #[validate(length(min = 1, max = 100, message = "must be between 1 and 100 characters"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub firstname: String,
+    #[validate(length(min = 1, max = 100, message = "must be between 1 and 100 characters"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub lastname: String,
+    #[validate(email(message = "must be a valid email address"))]
+    #[schema(format = Email)]
     pub email: String,
+    #[validate(length(min = 8, max = 72, message = "must be between 8 and 72 characters"))]
+    #[schema(format = Password, min_length = 8, max_length = 72)]
     pub password: String,
+    #[serde(default)]
+    pub role: Option<Role>,
 }
 
-#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateUserRequest {
     pub id: Option<i32>,
+    #[validate(length(min = 1, max = 100, message = "must be between 1 and 100 characters"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub firstname: Option<String>,
+    #[validate(length(min = 1, max = 100, message = "must be between 1 and 100 characters"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub lastname: Option<String>,
    
+    #[validate(email(message = "must be a valid email address"))]
+    #[schema(format = Email)]
     pub email: Option<String>, 
+    #[validate(length(min = 8, max = 72, message = "must be between 8 and 72 characters"))]
+    #[schema(format = Password, min_length = 8, max_length = 72)]
     pub password: Option<String>,
 }
 
+#[derive(Debug, Clone,  Serialize, Deserialize, ToSchema, Validate)]
+pub struct UpdateUserRoleRequest {
+    pub id: Option<i32>,
+    pub role: Role,
+}
@@ -7,13 +7,73 @@ use utoipa::ToSchema;
 pub struct CategoryResponse {
     pub id: i32,
     pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub sort_order: i32,
+    pub parent_id: Option<i32>,
+    /// Number of published posts filed directly under this category
+    pub post_count: i64,
+    /// Path from the top-level category down to this one, itself included
+    pub breadcrumbs: Vec<CategoryBreadcrumb>,
 }
 
-impl From<categories::Model> for CategoryResponse {
-    fn from(category: categories::Model) -> Self {
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CategoryBreadcrumb {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+}
+
+/// A category with its subcategories nested below it.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryTreeResponse {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    pub sort_order: i32,
+    pub parent_id: Option<i32>,
+    #[schema(no_recursion)]
+    pub children: Vec<CategoryTreeResponse>,
+}
+
+/// What merging one category into another changed.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryMergeResponse {
+    /// The merged category, which no longer exists
+    pub source_id: i32,
+    pub target_id: i32,
+    pub posts_moved: u64,
+    /// Post revisions now pointing at the target, so restoring them keeps working
+    pub revisions_moved: u64,
+    /// Direct subcategories now nested under the target
+    pub subcategories_moved: u64,
+}
+
+impl CategoryResponse {
+    pub fn new(
+        category: categories::Model,
+        post_count: i64,
+        breadcrumbs: Vec<CategoryBreadcrumb>,
+    ) -> Self {
         CategoryResponse {
             id: category.id,
             name: category.name,
+            slug: category.slug,
+            description: category.description,
+            sort_order: category.sort_order,
+            parent_id: category.parent_id,
+            post_count,
+            breadcrumbs,
+        }
+    }
+}
+
+impl From<&categories::Model> for CategoryBreadcrumb {
+    fn from(category: &categories::Model) -> Self {
+        CategoryBreadcrumb {
+            id: category.id,
+            name: category.name.clone(),
+            slug: category.slug.clone(),
         }
     }
 }
@@ -1,15 +1,42 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 
-use crate::entities::comments;
+use crate::entities::{comments, sea_orm_active_enums::CommentStatus};
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct CommentResponse {
     pub id: i32,
     pub id_post_comment: i32,
-    pub user_name_comment: String,
+    pub user_id: Option<i32>,
+    pub parent_id: Option<i32>,
     pub comment: String,
+    /// Only `approved` comments are shown to readers
+    pub status: CommentStatus,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+/// Comment settings of a post.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentSettingsResponse {
+    pub post_id: i32,
+    pub require_approval: bool,
+}
+
+/// A comment with its replies nested below it.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentTreeResponse {
+    pub id: i32,
+    pub id_post_comment: i32,
+    pub user_id: Option<i32>,
+    pub parent_id: Option<i32>,
+    pub comment: String,
+    /// 0 for top-level comments
+    pub depth: i32,
+    #[schema(no_recursion)]
+    pub replies: Vec<CommentTreeResponse>,
 }
 
 impl From<comments::Model> for CommentResponse {
@@ -17,8 +44,11 @@ impl From<comments::Model> for CommentResponse {
         CommentResponse {
             id: comment.id,
             id_post_comment: comment.id_post_comment,
-            user_name_comment: comment.user_name_comment,
+            user_id: comment.user_id,
+            parent_id: comment.parent_id,
             comment: comment.comment,
+            status: comment.status,
+            created_at: comment.created_at,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::entities::{
+    comment_filter_rules,
+    sea_orm_active_enums::{FilterAction, FilterRuleKind},
+};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentFilterRuleResponse {
+    pub id: i32,
+    pub kind: FilterRuleKind,
+    pub pattern: String,
+    pub action: FilterAction,
+    /// Comments this rule has matched
+    pub hits: i64,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<comment_filter_rules::Model> for CommentFilterRuleResponse {
+    fn from(rule: comment_filter_rules::Model) -> Self {
+        CommentFilterRuleResponse {
+            id: rule.id,
+            kind: rule.kind,
+            pattern: rule.pattern,
+            action: rule.action,
+            hits: rule.hits,
+            created_at: rule.created_at,
+        }
+    }
+}
+
+/// Counters of one filter since this instance started; they are not stored,
+/// so a restart resets them and every replica reports its own.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CommentFilterStatsResponse {
+    pub filter: String,
+    /// Comments the filter looked at
+    pub checked: u64,
+    /// Comments it held for moderation
+    pub held: u64,
+    /// Comments it filed as spam
+    pub spam: u64,
+    /// Checks that failed; the comment is held when a filter fails
+    pub errors: u64,
+}
@@ -3,8 +3,12 @@ use serde::Serialize;
 #[derive(Serialize, Clone)]
 pub struct UploadResponse {
     pub message: String,
+    /// Storage key, e.g. `2025-01-31/<uuid>.png`.
     pub file_name: String,
-    pub file_path: String,
+    pub url: String,
+    pub media_id: i32,
+    /// The same content was already in the media library and was reused.
+    pub deduplicated: bool,
 }
 
 #[derive(Serialize)]
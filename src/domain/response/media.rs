@@ -0,0 +1,41 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::entities::media;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MediaResponse {
+    pub id: i32,
+    pub url: String,
+    /// Storage key, e.g. `2025-01-31/<uuid>.png`
+    pub path: String,
+    /// Name of the file as uploaded
+    pub file_name: String,
+    pub mime: String,
+    /// Size in bytes of the stored image
+    pub size: i64,
+    pub width: i32,
+    pub height: i32,
+    /// Uploader; `None` once their account is deleted
+    pub user_id: Option<i32>,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl MediaResponse {
+    pub fn new(media: media::Model, url: String) -> Self {
+        MediaResponse {
+            id: media.id,
+            url,
+            path: media.path,
+            file_name: media.file_name,
+            mime: media.mime,
+            size: media.size,
+            width: media.width,
+            height: media.height,
+            user_id: media.user_id,
+            created_at: media.created_at,
+        }
+    }
+}
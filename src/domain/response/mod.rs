@@ -4,20 +4,38 @@ use serde::Serialize;
 use std::fmt::Formatter;
 use utoipa::ToSchema;
 
+mod auth;
 mod category;
 mod comment;
+mod comment_filter;
 mod file;
+mod media;
 mod pagination;
 mod post;
+mod post_revision;
+mod problem;
+mod scheduler;
+mod tag;
+mod upload_gc;
 mod user;
 
-use crate::utils::AppError;
-
-pub use self::category::CategoryResponse;
-pub use self::comment::CommentResponse;
+pub use self::auth::TokenResponse;
+pub use self::category::{
+    CategoryBreadcrumb, CategoryMergeResponse, CategoryResponse, CategoryTreeResponse,
+};
+pub use self::comment::{CommentResponse, CommentSettingsResponse, CommentTreeResponse};
+pub use self::comment_filter::{CommentFilterRuleResponse, CommentFilterStatsResponse};
 pub use self::file::{DeleteResponse, UploadResponse};
+pub use self::media::MediaResponse;
 pub use self::pagination::Pagination;
-pub use self::post::{PostRelationResponse, PostResponse};
+pub use self::post::{PostRelationResponse, PostResponse, PostSlugLookup};
+pub use self::post_revision::{
+    DiffLineResponse, DiffOp, PostRevisionResponse, RevisionDiffResponse,
+};
+pub use self::problem::{FieldError, ProblemDetails};
+pub use self::scheduler::SchedulerStatusResponse;
+pub use self::tag::TagResponse;
+pub use self::upload_gc::{OrphanedFileResponse, UploadGcReportResponse, UploadGcStatusResponse};
 pub use self::user::UserResponse;
 
 #[derive(Debug, Serialize, Clone, ToSchema)]
@@ -52,45 +70,3 @@ impl<T: Serialize> fmt::Display for ApiResponsePagination<T> {
         }
     }
 }
-
-#[derive(Debug, Serialize, ToSchema)]
-pub struct ErrorResponse {
-    pub status: String,
-    pub message: String,
-}
-
-impl From<AppError> for ErrorResponse {
-    fn from(error: AppError) -> Self {
-        let (status, message) = match error {
-            AppError::DbError(_) => ("error".to_string(), "Database error occurred".to_string()),
-            AppError::HashingError(_) => (
-                "error".to_string(),
-                "Error during password hashing".to_string(),
-            ),
-            AppError::NotFound(ref msg) => ("error".to_string(), msg.clone()),
-            AppError::TokenExpiredError => ("error".to_string(), "Token has expired".to_string()),
-            AppError::TokenValidationError => {
-                ("error".to_string(), "Token validation failed".to_string())
-            }
-            AppError::TokenGenerationError(_) => {
-                ("error".to_string(), "Token generation failed".to_string())
-            }
-            AppError::BcryptError(ref msg) => {
-                ("error".to_string(), format!("Bcrypt error: {}", msg))
-            }
-            AppError::InvalidCredentials => {
-                ("error".to_string(), "Invalid credentials".to_string())
-            }
-            AppError::EmailAlreadyExists => {
-                ("error".to_string(), "Email already exists".to_string())
-            }
-        };
-        ErrorResponse { status, message }
-    }
-}
-
-impl fmt::Display for ErrorResponse {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Status: {}, Message: {}", self.status, self.message)
-    }
-}
@@ -7,4 +7,7 @@ pub struct Pagination {
     pub page_size: i32,
     pub total_items: i64,
     pub total_pages: i32,
+    /// Cursor of the following page for cursor-paginated listings; absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<i32>,
 }
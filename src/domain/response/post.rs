@@ -1,17 +1,43 @@
+use std::collections::BTreeMap;
+
+use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use crate::entities::{comments, posts};
+use crate::{
+    domain::ApiResponse,
+    entities::{comments, posts, sea_orm_active_enums::PostStatus},
+};
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct PostResponse {
     pub id: i32,
     pub title: String,
+    pub slug: String,
     pub body: String,
     pub img: String,
+    /// Downscaled copies of `img` in `srcset` syntax, keyed by MIME type
+    #[schema(example = json!({"image/webp": "https://example.com/media/2025-01-31/a-320w.webp 320w, https://example.com/media/2025-01-31/a-768w.webp 768w"}))]
+    pub srcset: BTreeMap<String, String>,
+    /// Media library entry of `img`; `None` for images uploaded before the library existed
+    pub media_id: Option<i32>,
     pub category_id: i32,
     pub user_id: i32,
     pub user_name: String,
+    pub status: PostStatus,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub published_at: Option<DateTimeWithTimeZone>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub publish_at: Option<DateTimeWithTimeZone>,
+    /// New comments are held for moderation
+    pub comments_require_approval: bool,
+    pub tags: Vec<String>,
+    /// Full-text relevance, only set for full-text searches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<f32>,
+    /// Body excerpt with matches wrapped in `<mark>`, only set for full-text searches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
 }
 
 impl From<posts::Model> for PostResponse {
@@ -19,22 +45,40 @@ impl From<posts::Model> for PostResponse {
         PostResponse {
             id: post.id,
             title: post.title,
+            slug: post.slug,
             body: post.body,
             img: post.img,
+            srcset: BTreeMap::new(),
+            media_id: post.media_id,
             category_id: post.category_id,
             user_id: post.user_id,
             user_name: post.user_name,
+            status: post.status,
+            published_at: post.published_at,
+            publish_at: post.publish_at,
+            comments_require_approval: post.comments_require_approval,
+            tags: Vec::new(),
+            rank: None,
+            headline: None,
         }
     }
 }
 
+/// Result of resolving a post by slug.
+#[derive(Debug)]
+pub enum PostSlugLookup {
+    Found(Box<ApiResponse<PostResponse>>),
+    /// The slug belonged to the post before a title change; holds its current slug.
+    Moved(String),
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct PostRelationResponse {
     pub post_id: i32,
     pub title: String,
     pub comment_id: i32,
     pub id_post_comment: i32,
-    pub user_name_comment: String,
+    pub user_id: Option<i32>,
     pub comment: String,
 }
 
@@ -45,7 +89,7 @@ impl PostRelationResponse {
             title: post.title.clone(),
             comment_id: comment.id,
             id_post_comment: comment.id_post_comment,
-            user_name_comment: comment.user_name_comment.clone(),
+            user_id: comment.user_id,
             comment: comment.comment.clone(),
         }
     }
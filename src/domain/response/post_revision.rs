@@ -0,0 +1,64 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::entities::post_revisions;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PostRevisionResponse {
+    pub id: i32,
+    pub post_id: i32,
+    pub revision: i32,
+    pub title: String,
+    pub body: String,
+    pub img: String,
+    pub media_id: Option<i32>,
+    pub category_id: i32,
+    pub edited_by: Option<i32>,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<post_revisions::Model> for PostRevisionResponse {
+    fn from(revision: post_revisions::Model) -> Self {
+        PostRevisionResponse {
+            id: revision.id,
+            post_id: revision.post_id,
+            revision: revision.revision,
+            title: revision.title,
+            body: revision.body,
+            img: revision.img,
+            media_id: revision.media_id,
+            category_id: revision.category_id,
+            edited_by: revision.edited_by,
+            created_at: revision.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DiffLineResponse {
+    pub op: DiffOp,
+    /// 1-based line number in the `from` revision
+    pub old_line: Option<usize>,
+    /// 1-based line number in the `to` revision
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevisionDiffResponse {
+    pub post_id: i32,
+    pub from: i32,
+    pub to: i32,
+    pub title_changed: bool,
+    pub lines: Vec<DiffLineResponse>,
+}
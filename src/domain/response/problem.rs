@@ -0,0 +1,25 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// RFC 7807 problem document returned for every failed request.
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    /// Stable machine-readable error code, e.g. `email_already_exists`.
+    pub code: String,
+    /// Per-field failures when the request body did not pass validation.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    /// Validator rule that failed, e.g. `length` or `email`.
+    pub code: String,
+    pub message: String,
+}
@@ -0,0 +1,15 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct SchedulerStatusResponse {
+    pub interval_seconds: u64,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub last_run_at: Option<DateTimeWithTimeZone>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub last_success_at: Option<DateTimeWithTimeZone>,
+    pub last_published: u64,
+    pub total_published: u64,
+    pub last_error: Option<String>,
+}
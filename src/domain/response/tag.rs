@@ -0,0 +1,21 @@
+use crate::entities::tags;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TagResponse {
+    pub id: i32,
+    pub name: String,
+    /// Number of published posts carrying this tag
+    pub post_count: i64,
+}
+
+impl TagResponse {
+    pub fn new(tag: tags::Model, post_count: i64) -> Self {
+        TagResponse {
+            id: tag.id,
+            name: tag.name,
+            post_count,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OrphanedFileResponse {
+    /// Storage key, e.g. `2025-01-31/<uuid>.png`
+    pub key: String,
+    pub size: u64,
+    #[schema(value_type = String, format = DateTime)]
+    pub last_modified: DateTimeWithTimeZone,
+    /// Unreferenced media entry the file belongs to, if any
+    pub media_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UploadGcReportResponse {
+    /// Nothing was deleted; the report lists what a real run would remove.
+    pub dry_run: bool,
+    pub grace_period_seconds: u64,
+    pub scanned_files: u64,
+    pub referenced_files: u64,
+    pub orphaned_files: Vec<OrphanedFileResponse>,
+    /// Media entries no post or revision uses any more
+    pub orphaned_media_ids: Vec<i32>,
+    pub orphaned_bytes: u64,
+    pub deleted_files: u64,
+    pub reclaimed_bytes: u64,
+    /// Files or media entries that could not be removed, with the reason
+    pub failures: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct UploadGcStatusResponse {
+    /// Zero when the background sweeper is disabled
+    pub interval_seconds: u64,
+    pub grace_period_seconds: u64,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub last_run_at: Option<DateTimeWithTimeZone>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub last_success_at: Option<DateTimeWithTimeZone>,
+    pub last_dry_run: bool,
+    pub last_deleted_files: u64,
+    pub last_reclaimed_bytes: u64,
+    pub total_deleted_files: u64,
+    pub total_reclaimed_bytes: u64,
+    pub last_error: Option<String>,
+}
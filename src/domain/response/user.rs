@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
-use crate::entities::users;
+use crate::entities::{sea_orm_active_enums::Role, users};
 
 
 #[derive(Debug, Deserialize, Serialize,  Clone, ToSchema)]
@@ -9,6 +9,7 @@ pub struct UserResponse {
     pub firstname: String,
     pub lastname: String,
     pub email: String,
+    pub role: Role,
 }
 
 impl From<users::Model> for UserResponse {
@@ -18,6 +19,7 @@ impl From<users::Model> for UserResponse {
             firstname: user.firstname,
             lastname: user.lastname,
             email: user.email,
+            role: user.role,
         }
     }
 }
\ No newline at end of file
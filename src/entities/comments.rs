@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
+use super::sea_orm_active_enums::CommentStatus;
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -8,12 +9,23 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub id_post_comment: i32,
-    pub user_name_comment: String,
     pub comment: String,
+    pub user_id: Option<i32>,
+    pub parent_id: Option<i32>,
+    pub status: CommentStatus,
+    pub created_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    SelfRef,
     #[sea_orm(
         belongs_to = "super::posts::Entity",
         from = "Column::IdPostComment",
@@ -22,6 +34,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Posts,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Users,
 }
 
 impl Related<super::posts::Entity> for Entity {
@@ -30,4 +50,10 @@ impl Related<super::posts::Entity> for Entity {
     }
 }
 
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
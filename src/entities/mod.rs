@@ -3,12 +3,31 @@
 pub mod prelude;
 
 pub mod categories;
+pub mod comment_filter_rules;
 pub mod comments;
+pub mod image_variants;
+pub mod media;
+pub mod post_revisions;
+pub mod post_slug_history;
+pub mod post_tags;
 pub mod posts;
+pub mod refresh_tokens;
+pub mod revoked_tokens;
+pub mod sea_orm_active_enums;
+pub mod tags;
 pub mod users;
 
 
 pub use users::Entity as Users;
 pub use categories::Entity as Categories;
 pub use posts::Entity as Posts;
-pub use comments::Entity as Comments;
\ No newline at end of file
+pub use comments::Entity as Comments;
+pub use comment_filter_rules::Entity as CommentFilterRules;
+pub use image_variants::Entity as ImageVariants;
+pub use media::Entity as Media;
+pub use post_revisions::Entity as PostRevisions;
+pub use post_slug_history::Entity as PostSlugHistory;
+pub use post_tags::Entity as PostTags;
+pub use tags::Entity as Tags;
+pub use refresh_tokens::Entity as RefreshTokens;
+pub use revoked_tokens::Entity as RevokedTokens;
@@ -1,6 +1,15 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
 pub use super::categories::Entity as Categories;
+pub use super::comment_filter_rules::Entity as CommentFilterRules;
 pub use super::comments::Entity as Comments;
+pub use super::image_variants::Entity as ImageVariants;
+pub use super::media::Entity as Media;
+pub use super::post_revisions::Entity as PostRevisions;
+pub use super::post_slug_history::Entity as PostSlugHistory;
+pub use super::post_tags::Entity as PostTags;
 pub use super::posts::Entity as Posts;
+pub use super::refresh_tokens::Entity as RefreshTokens;
+pub use super::revoked_tokens::Entity as RevokedTokens;
+pub use super::tags::Entity as Tags;
 pub use super::users::Entity as Users;
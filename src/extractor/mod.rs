@@ -0,0 +1,4 @@
+mod post_form;
+mod validated;
+
+pub use self::validated::{FromMultipart, ValidatedJson, ValidatedMultipart, ValidatedQuery};
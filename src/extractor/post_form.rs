@@ -0,0 +1,162 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use axum::{
+    extract::{
+        multipart::{Field, MultipartError},
+        Multipart,
+    },
+    http::StatusCode,
+};
+use tokio::{fs::File, io::AsyncWriteExt};
+use uuid::Uuid;
+
+use super::FromMultipart;
+use crate::{
+    domain::{PostForm, UploadedFile},
+    utils::AppError,
+};
+
+const MAX_TITLE_BYTES: usize = 1024;
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+const MAX_ID_LIST_BYTES: usize = 4 * 1024;
+const MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+#[async_trait]
+impl FromMultipart for PostForm {
+    async fn from_multipart(mut multipart: Multipart) -> Result<Self, AppError> {
+        let mut title = None;
+        let mut body = None;
+        let mut category_id = None;
+        let mut tag_ids: Option<Vec<i32>> = None;
+        let mut file = None;
+
+        while let Some(field) = multipart.next_field().await.map_err(multipart_error)? {
+            match field.name() {
+                Some("title") => title = Some(read_text(field, "title", MAX_TITLE_BYTES).await?),
+                Some("body") => body = Some(read_text(field, "body", MAX_BODY_BYTES).await?),
+                Some("category_id") => {
+                    let value = read_text(field, "category_id", MAX_ID_LIST_BYTES).await?;
+                    category_id = Some(value.trim().parse::<i32>().map_err(|_| {
+                        AppError::BadRequest(format!("Invalid category_id '{}'", value))
+                    })?);
+                }
+                Some("tag_ids") => {
+                    let value = read_text(field, "tag_ids", MAX_ID_LIST_BYTES).await?;
+                    tag_ids
+                        .get_or_insert_with(Vec::new)
+                        .extend(parse_tag_ids(&value)?);
+                }
+                Some("file") => file = spool_file(field).await?,
+                _ => (),
+            }
+        }
+
+        Ok(PostForm {
+            title: title.ok_or_else(|| missing("title"))?,
+            body: body.ok_or_else(|| missing("body"))?,
+            category_id: category_id.ok_or_else(|| missing("category_id"))?,
+            tag_ids,
+            file,
+        })
+    }
+}
+
+fn missing(name: &str) -> AppError {
+    AppError::BadRequest(format!("Missing required field '{}'", name))
+}
+
+fn multipart_error(err: MultipartError) -> AppError {
+    if err.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        AppError::PayloadTooLarge(err.body_text())
+    } else {
+        AppError::BadRequest(err.body_text())
+    }
+}
+
+async fn read_text(mut field: Field<'_>, name: &str, limit: usize) -> Result<String, AppError> {
+    let mut buffer = Vec::new();
+
+    while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+        if buffer.len() + chunk.len() > limit {
+            return Err(AppError::PayloadTooLarge(format!(
+                "Field '{}' must be at most {} bytes",
+                name, limit
+            )));
+        }
+        buffer.extend_from_slice(&chunk);
+    }
+
+    String::from_utf8(buffer)
+        .map_err(|_| AppError::BadRequest(format!("Field '{}' must be valid UTF-8", name)))
+}
+
+// The file is written to a temporary file chunk by chunk so large uploads never sit in memory.
+// Browsers send an empty part when no file was picked; that counts as no file.
+async fn spool_file(mut field: Field<'_>) -> Result<Option<UploadedFile>, AppError> {
+    let file_name = field
+        .file_name()
+        .filter(|name| !name.is_empty())
+        .map(ToString::to_string);
+    let content_type = field
+        .content_type()
+        .unwrap_or("application/octet-stream")
+        .to_string();
+
+    let Some(file_name) = file_name else {
+        // Drain the part so a plain text value under `file` is treated as absent
+        while field.chunk().await.map_err(multipart_error)?.is_some() {}
+        return Ok(None);
+    };
+
+    let mut upload = UploadedFile {
+        file_name,
+        content_type,
+        path: temp_upload_path(),
+        size: 0,
+    };
+
+    let mut out = File::create(&upload.path).await.map_err(|e| {
+        AppError::StorageError(format!("Failed to create temporary upload: {}", e))
+    })?;
+
+    while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+        upload.size += chunk.len() as u64;
+        if upload.size > MAX_FILE_BYTES {
+            return Err(AppError::PayloadTooLarge(format!(
+                "Field 'file' must be at most {} bytes",
+                MAX_FILE_BYTES
+            )));
+        }
+        out.write_all(&chunk).await.map_err(|e| {
+            AppError::StorageError(format!("Failed to write temporary upload: {}", e))
+        })?;
+    }
+
+    out.flush().await.map_err(|e| {
+        AppError::StorageError(format!("Failed to write temporary upload: {}", e))
+    })?;
+
+    if upload.size == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(upload))
+}
+
+fn temp_upload_path() -> PathBuf {
+    std::env::temp_dir().join(format!("upload-{}", Uuid::new_v4()))
+}
+
+// Tag ids arrive as a comma-separated list and the field may be repeated.
+fn parse_tag_ids(value: &str) -> Result<Vec<i32>, AppError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            id.parse()
+                .map_err(|_| AppError::BadRequest(format!("Invalid tag id '{}'", id)))
+        })
+        .collect()
+}
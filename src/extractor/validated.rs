@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use axum::{
+    extract::{
+        rejection::JsonRejection, FromRequest, FromRequestParts, Multipart, Query, Request,
+    },
+    http::request::Parts,
+    Json,
+};
+use serde::de::DeserializeOwned;
+use validator::Validate;
+
+use crate::utils::AppError;
+
+/// `Json<T>` that also runs `T::validate`, answering 422 with per-field errors.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|rejection| match rejection {
+                // Well-formed JSON with the wrong shape is a semantic error, not a syntax one
+                JsonRejection::JsonDataError(err) => AppError::Validation(err.body_text()),
+                other => AppError::BadRequest(other.body_text()),
+            })?;
+
+        value.validate()?;
+
+        Ok(Self(value))
+    }
+}
+
+/// `Query<T>` that also runs `T::validate`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(value) = Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection| AppError::BadRequest(rejection.body_text()))?;
+
+        value.validate()?;
+
+        Ok(Self(value))
+    }
+}
+
+/// A form that can be read field by field from a `multipart/form-data` body.
+#[async_trait]
+pub trait FromMultipart: Sized {
+    async fn from_multipart(multipart: Multipart) -> Result<Self, AppError>;
+}
+
+/// Multipart counterpart of [`ValidatedJson`].
+#[derive(Debug)]
+pub struct ValidatedMultipart<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedMultipart<T>
+where
+    T: FromMultipart + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let multipart = Multipart::from_request(req, state)
+            .await
+            .map_err(|rejection| AppError::BadRequest(rejection.body_text()))?;
+
+        let value = T::from_multipart(multipart).await?;
+        value.validate()?;
+
+        Ok(Self(value))
+    }
+}
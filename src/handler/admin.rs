@@ -0,0 +1,234 @@
+use crate::{
+    domain::{
+        ApiResponse, CommentFilterRuleResponse, CommentFilterStatsResponse,
+        CreateCommentFilterRuleRequest, ProblemDetails, RunUploadGcRequest,
+        SchedulerStatusResponse, UpdateCommentFilterRuleRequest, UploadGcReportResponse,
+        UploadGcStatusResponse,
+    },
+    entities::sea_orm_active_enums::Role,
+    extractor::{ValidatedJson, ValidatedQuery},
+    middleware::{jwt, rbac},
+    state::AppState,
+    utils::AppError,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/scheduler",
+    responses(
+        (status = 200, description = "Status of the scheduled publishing job", body = ApiResponse<SchedulerStatusResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_scheduler_status(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let status = data.di_container.publish_scheduler.status().await;
+
+    Ok((StatusCode::OK, Json(json!(status))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/uploads/gc",
+    responses(
+        (status = 200, description = "Status and reclaimed-space totals of the upload garbage collector", body = ApiResponse<UploadGcStatusResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_upload_gc_status(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let status = data.di_container.upload_gc.status().await;
+
+    Ok((StatusCode::OK, Json(json!(status))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/uploads/gc",
+    params(RunUploadGcRequest),
+    responses(
+        (status = 200, description = "Uploads no post, revision or media entry references, older than the grace period", body = ApiResponse<UploadGcReportResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 500, description = "Storage could not be listed", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn run_upload_gc(
+    State(data): State<Arc<AppState>>,
+    ValidatedQuery(params): ValidatedQuery<RunUploadGcRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = data.di_container.upload_gc.run(params.dry_run).await?;
+
+    Ok((StatusCode::OK, Json(json!(report))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/comment-filters",
+    responses(
+        (status = 200, description = "Per-filter counters of checked, held and spam comments since the server started", body = ApiResponse<Vec<CommentFilterStatsResponse>>),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_comment_filter_stats(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let stats = data.di_container.comment_filter_service.get_stats().await;
+
+    Ok((StatusCode::OK, Json(json!(stats))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/comment-filters/rules",
+    responses(
+        (status = 200, description = "Blocked words and patterns with their hit counts", body = ApiResponse<Vec<CommentFilterRuleResponse>>),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_comment_filter_rules(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let rules = data.di_container.comment_filter_service.get_rules().await?;
+
+    Ok((StatusCode::OK, Json(json!(rules))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/comment-filters/rules",
+    request_body = CreateCommentFilterRuleRequest,
+    responses(
+        (status = 201, description = "Rule created", body = ApiResponse<CommentFilterRuleResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 409, description = "The same rule already exists", body = ProblemDetails),
+        (status = 422, description = "Blank pattern or invalid regular expression", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn create_comment_filter_rule(
+    State(data): State<Arc<AppState>>,
+    ValidatedJson(body): ValidatedJson<CreateCommentFilterRuleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let rule = data
+        .di_container
+        .comment_filter_service
+        .create_rule(&body)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(json!(rule))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/comment-filters/rules/{id}",
+    params(
+        ("id" = i32, Path, description = "Rule ID")
+    ),
+    request_body = UpdateCommentFilterRuleRequest,
+    responses(
+        (status = 200, description = "Rule updated", body = ApiResponse<CommentFilterRuleResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 404, description = "Rule not found", body = ProblemDetails),
+        (status = 409, description = "The same rule already exists", body = ProblemDetails),
+        (status = 422, description = "Blank pattern or invalid regular expression", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn update_comment_filter_rule(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    ValidatedJson(mut body): ValidatedJson<UpdateCommentFilterRuleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    body.id = Some(id);
+
+    let rule = data
+        .di_container
+        .comment_filter_service
+        .update_rule(&body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(rule))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/comment-filters/rules/{id}",
+    params(
+        ("id" = i32, Path, description = "Rule ID")
+    ),
+    responses(
+        (status = 200, description = "Rule deleted", body = Value),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 404, description = "Rule not found", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn delete_comment_filter_rule(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let response = data
+        .di_container
+        .comment_filter_service
+        .delete_rule(id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+pub fn admin_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/admin/scheduler", get(get_scheduler_status))
+        .route("/api/admin/uploads/gc", get(get_upload_gc_status))
+        .route("/api/admin/uploads/gc", post(run_upload_gc))
+        .route("/api/admin/comment-filters", get(get_comment_filter_stats))
+        .route("/api/admin/comment-filters/rules", get(get_comment_filter_rules))
+        .route("/api/admin/comment-filters/rules", post(create_comment_filter_rule))
+        .route("/api/admin/comment-filters/rules/{id}", put(update_comment_filter_rule))
+        .route("/api/admin/comment-filters/rules/{id}", delete(delete_comment_filter_rule))
+        .route_layer(middleware::from_fn_with_state(Role::Admin, rbac::require_role))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state)
+}
@@ -4,9 +4,9 @@ use std::sync::Arc;
 use axum::{
     extract::State, http::StatusCode, middleware, response::IntoResponse, routing::{get, post}, Extension, Json
 };
-use serde_json::{json, Value};
+use serde_json::json;
 use utoipa_axum::router::OpenApiRouter;
-use crate::{domain::{ApiResponse, LoginRequest, RegisterRequest, UserResponse}, middleware::jwt, state::AppState};
+use crate::{config::Claims, domain::{ApiResponse, LoginRequest, LogoutRequest, ProblemDetails, RefreshTokenRequest, RegisterRequest, TokenResponse, UserResponse}, extractor::ValidatedJson, middleware::jwt, state::AppState, utils::AppError};
 
 
 
@@ -26,25 +26,19 @@ pub async fn health_checker_handler() -> impl IntoResponse {
     path = "/api/auth/register",
     request_body = RegisterRequest,
     responses(
-        (status = 200, description = "Login successful", body = ApiResponse<UserResponse>),
-        (status = 401, description = "Unauthorized")
+        (status = 200, description = "User registered successfully", body = ApiResponse<UserResponse>),
+        (status = 409, description = "Email already exists", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     tag = "auth"
 )]
 pub async fn register_user_handler(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<RegisterRequest>
-) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
-    match data.di_container.auth_service.register_user(&body).await {
-        Ok(response) => Ok((
-            StatusCode::OK,
-            Json(json!(response))
-        )),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!(e))
-        ))
-    }
+    ValidatedJson(body): ValidatedJson<RegisterRequest>
+) -> Result<impl IntoResponse, AppError> {
+    let response = data.di_container.auth_service.register_user(&body).await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
 }
 
 #[utoipa::path(
@@ -52,32 +46,76 @@ pub async fn register_user_handler(
     path = "/api/auth/login",
     request_body = LoginRequest,
     responses(
-        (status = 200, description = "Login successful", body = ApiResponse<String>),
-        (status = 401, description = "Unauthorized")
+        (status = 200, description = "Login successful", body = ApiResponse<TokenResponse>),
+        (status = 401, description = "Invalid credentials", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     tag = "auth"
 )]
 pub async fn login_user_handler(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<LoginRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
-    match data.di_container.auth_service.login_user(&body).await {
-        Ok(response) => Ok((
-            StatusCode::OK,
-            Json(json!(response))
-        )),
-        Err(e) => Err((
-            StatusCode::UNAUTHORIZED,
-            Json(json!(e))
-        ))
-    }
+    ValidatedJson(body): ValidatedJson<LoginRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response = data.di_container.auth_service.login_user(&body).await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/refresh",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 200, description = "Token refreshed successfully", body = ApiResponse<TokenResponse>),
+        (status = 401, description = "Invalid, expired or reused refresh token", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    tag = "auth"
+)]
+pub async fn refresh_token_handler(
+    State(data): State<Arc<AppState>>,
+    ValidatedJson(body): ValidatedJson<RefreshTokenRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response = data.di_container.auth_service.refresh_token(&body).await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout",
+    request_body = LogoutRequest,
+    responses(
+        (status = 200, description = "Logout successful", body = Value),
+        (status = 401, description = "Unauthorized", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+)]
+pub async fn logout_handler(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<LogoutRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response = data
+        .di_container
+        .auth_service
+        .logout_user(&claims, &body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
 }
 
 #[utoipa::path(
     get,
     path = "/api/users/me",
     responses(
-        (status = 200, description = "Get Me user", body = ApiResponse<UserResponse>)
+        (status = 200, description = "Get Me user", body = ApiResponse<UserResponse>),
+        (status = 401, description = "Unauthorized", body = ProblemDetails),
+        (status = 404, description = "User not found", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -87,31 +125,15 @@ pub async fn login_user_handler(
 pub async fn get_me_handler(
     State(data): State<Arc<AppState>>,
     Extension(user_id): Extension<i64>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    
-    match data.di_container.user_service.find_by_id(user_id as i32).await {
-        Ok(Some(user)) => {
-            Ok((
-                StatusCode::OK,
-                Json(json!(user))
-            ))
-        },
-        Ok(None) => {
-            Err((
-                StatusCode::NOT_FOUND,
-                Json(json!({
-                    "status": "fail",
-                    "message": "User not found"
-                }))
-            ))
-        },
-        Err(e) => {
-            Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(json!(e))
-            ))
-        }
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let user = data
+        .di_container
+        .user_service
+        .find_by_id(user_id as i32)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(user))))
 }
 
 
@@ -120,6 +142,12 @@ pub fn auth_routes(app_state: Arc<AppState>) -> OpenApiRouter {
         .route("/api/healthchecker", get(health_checker_handler))
         .route("/api/auth/register", post(register_user_handler))
         .route("/api/auth/login", post(login_user_handler))
+        .route("/api/auth/refresh", post(refresh_token_handler))
+        .route(
+            "/api/auth/logout",
+            post(logout_handler)
+            .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth)).with_state(app_state.clone())
+        )
         .route(
             "/api/users/me",
             get(get_me_handler)
@@ -1,13 +1,17 @@
 use crate::{
     domain::{
-        ApiResponse, ApiResponsePagination, CategoryResponse, CreateCategoryRequest,
-        FindAllCategoryRequest, UpdateCategoryRequest,
+        ApiResponse, ApiResponsePagination, CategoryMergeResponse, CategoryResponse,
+        CategoryTreeResponse, CreateCategoryRequest, DeleteCategoryRequest, FindAllCategoryRequest,
+        MergeCategoryRequest, ProblemDetails, UpdateCategoryRequest,
     },
-    middleware::jwt,
+    extractor::{ValidatedJson, ValidatedQuery},
+    entities::sea_orm_active_enums::Role,
+    middleware::{jwt, rbac},
     state::AppState,
+    utils::{AppError, Permission},
 };
 use axum::{
-    extract::{Extension, Path, Query, State},
+    extract::{Extension, Path, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
@@ -23,7 +27,8 @@ use utoipa_axum::router::OpenApiRouter;
     path = "/api/categories",
     params(FindAllCategoryRequest),
     responses(
-        (status = 200, description = "List all category successfully", body = ApiResponsePagination<Vec<CategoryResponse>>)
+        (status = 200, description = "List all category successfully", body = ApiResponsePagination<Vec<CategoryResponse>>),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -32,17 +37,31 @@ use utoipa_axum::router::OpenApiRouter;
 )]
 pub async fn get_categories(
     State(data): State<Arc<AppState>>,
-    Query(params): Query<FindAllCategoryRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data
+    ValidatedQuery(params): ValidatedQuery<FindAllCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let categories = data
         .di_container
         .category_service
         .get_categories(params)
-        .await
-    {
-        Ok(categories) => Ok((StatusCode::OK, Json(json!(categories)))),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(categories))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/categories/tree",
+    responses(
+        (status = 200, description = "Categories nested under their parents", body = ApiResponse<Vec<CategoryTreeResponse>>)
+    ),
+    tag = "category"
+)]
+pub async fn get_category_tree(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let tree = data.di_container.category_service.get_category_tree().await?;
+
+    Ok((StatusCode::OK, Json(json!(tree))))
 }
 
 #[utoipa::path(
@@ -57,32 +76,58 @@ pub async fn get_categories(
     ),
     responses(
         (status = 200, description = "Successfully retrieved category details", body = ApiResponse<CategoryResponse>),
-        (status = 500, description = "Internal server error", body = String),
+        (status = 500, description = "Internal server error", body = ProblemDetails),
     )
 )]
 pub async fn get_category(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
     Extension(_user_id): Extension<i64>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.category_service.get_category(id).await {
-        Ok(Some(category)) => Ok((StatusCode::OK, Json(json!(category)))),
-        Ok(None) => Err((
-            StatusCode::NOT_FOUND,
-            Json(json!({
-                "status": "fail",
-                "message": "Category not found"
-            })),
-        )),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let category = data
+        .di_container
+        .category_service
+        .get_category(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(category))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/categories/{slug}",
+    params(
+        ("slug" = String, Path, description = "Category slug")
+    ),
+    responses(
+        (status = 200, description = "Get category by slug", body = ApiResponse<CategoryResponse>),
+        (status = 404, description = "Category not found", body = ProblemDetails)
+    ),
+    tag = "category"
+)]
+pub async fn get_category_by_slug(
+    State(data): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let category = data
+        .di_container
+        .category_service
+        .get_category_by_slug(&slug)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(category))))
 }
 
 #[utoipa::path(
     post,
     path = "/api/categories",
     responses(
-        (status = 200, description = "Create category", body = ApiResponse<CategoryResponse>)
+        (status = 200, description = "Create category", body = ApiResponse<CategoryResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 409, description = "Name or slug already in use", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -91,17 +136,15 @@ pub async fn get_category(
 )]
 pub async fn create_category(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<CreateCategoryRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data
+    ValidatedJson(body): ValidatedJson<CreateCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let category = data
         .di_container
         .category_service
         .create_category(&body)
-        .await
-    {
-        Ok(category) => Ok((StatusCode::CREATED, Json(json!(category)))),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(json!(category))))
 }
 
 #[utoipa::path(
@@ -111,7 +154,10 @@ pub async fn create_category(
             ("id" = i32, Path, description = "Category ID")
     ),
     responses(
-        (status = 200, description = "Delete category", body = ApiResponse<CategoryResponse>)
+        (status = 200, description = "Delete category", body = ApiResponse<CategoryResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 409, description = "Name or slug already in use", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -121,36 +167,32 @@ pub async fn create_category(
 pub async fn update_category(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Json(mut body): Json<UpdateCategoryRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    ValidatedJson(mut body): ValidatedJson<UpdateCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
     body.id = Some(id);
 
-    match data
+    let category = data
         .di_container
         .category_service
         .update_category(&body)
-        .await
-    {
-        Ok(Some(category)) => Ok((StatusCode::OK, Json(json!(category)))),
-        Ok(None) => Err((
-            StatusCode::NOT_FOUND,
-            Json(json!({
-                "status": "fail",
-                "message": "Category not found"
-            })),
-        )),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+        .await?
+        .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(category))))
 }
 
 #[utoipa::path(
     delete,
     path = "/api/categories/{id}",
     params(
-        ("id" = i32, Path, description = "Category ID")
+        ("id" = i32, Path, description = "Category ID"),
+        DeleteCategoryRequest
     ),
     responses(
-        (status = 200, description = "Delete category", body = Value)
+        (status = 200, description = "Delete category, reporting what was reassigned", body = ApiResponse<Option<CategoryMergeResponse>>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 409, description = "Category still has posts or subcategories", body = ProblemDetails),
+        (status = 422, description = "Invalid reassignment target", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -161,29 +203,83 @@ pub async fn delete_category(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
     Extension(_user_id): Extension<i64>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.category_service.delete_category(id).await {
-        Ok(_) => Ok((
-            StatusCode::OK,
-            Json(json!({
-                "status": "success",
-                "message": "Category deleted successfully"
-            })),
-        )),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+    ValidatedQuery(params): ValidatedQuery<DeleteCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response = data
+        .di_container
+        .category_service
+        .delete_category(id, params.reassign_to)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/categories/{id}/merge",
+    params(
+        ("id" = i32, Path, description = "Category to merge and delete")
+    ),
+    request_body = MergeCategoryRequest,
+    responses(
+        (status = 200, description = "Posts and subcategories moved to the target and the category deleted", body = ApiResponse<CategoryMergeResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 404, description = "Category not found", body = ProblemDetails),
+        (status = 422, description = "Invalid merge target", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "category"
+)]
+pub async fn merge_category(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    ValidatedJson(body): ValidatedJson<MergeCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = data
+        .di_container
+        .category_service
+        .merge_category(id, body.target_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(report))))
 }
 
 pub fn category_routes(app_state: Arc<AppState>) -> OpenApiRouter {
     let protected_routes = OpenApiRouter::new()
         .route("/api/categories/{id}", get(get_category))
-        .route("/api/categories", post(create_category))
-        .route("/api/categories/{id}", put(update_category))
-        .route("/api/categories/{id}", delete(delete_category))
+        .route(
+            "/api/categories",
+            post(create_category).route_layer(middleware::from_fn_with_state(
+                Permission::ManageCategories,
+                rbac::require_permission,
+            )),
+        )
+        .route(
+            "/api/categories/{id}",
+            put(update_category).route_layer(middleware::from_fn_with_state(
+                Permission::ManageCategories,
+                rbac::require_permission,
+            )),
+        )
+        .route(
+            "/api/categories/{id}",
+            delete(delete_category)
+                .route_layer(middleware::from_fn_with_state(Role::Admin, rbac::require_role)),
+        )
+        .route(
+            "/api/categories/{id}/merge",
+            post(merge_category)
+                .route_layer(middleware::from_fn_with_state(Role::Admin, rbac::require_role)),
+        )
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone());
 
-    let public_routes = OpenApiRouter::new().route("/categories", get(get_categories));
+    let public_routes = OpenApiRouter::new()
+        .route("/categories", get(get_categories))
+        .route("/categories/{slug}", get(get_category_by_slug))
+        .route("/api/categories/tree", get(get_category_tree));
 
     OpenApiRouter::new()
         .merge(protected_routes)
@@ -4,14 +4,17 @@ use axum::{
     middleware,
     response::IntoResponse,
     routing::{get, post, put, delete},
-    Json
+    Extension, Json
 };
 use serde_json::json;
 use utoipa_axum::router::OpenApiRouter;
 use std::sync::Arc;
 use crate::{
-    middleware::jwt,
-    domain::{ApiResponse, CommentResponse, CreateCommentRequest, UpdateCommentRequest},
+    config::Claims,
+    middleware::{jwt, rbac},
+    utils::{AppError, Permission},
+    domain::{ApiResponse, ApiResponsePagination, CommentResponse, CommentSettingsResponse, CommentTreeResponse, CreateCommentRequest, FindModerationQueueRequest, FindPostCommentsRequest, ModerateCommentsRequest, ProblemDetails, UpdateCommentRequest, UpdateCommentSettingsRequest},
+    extractor::{ValidatedJson, ValidatedQuery},
     state::AppState,
 };
 
@@ -19,7 +22,7 @@ use crate::{
     get,
     path = "/api/comments",
     responses(
-        (status = 200, description = "Get all comments", body = ApiResponse<Vec<CommentResponse>>)
+        (status = 200, description = "Get all approved comments", body = ApiResponse<Vec<CommentResponse>>)
     ),
     security(
         ("bearer_auth" = [])
@@ -28,18 +31,10 @@ use crate::{
 )]
 pub async fn get_comments(
     State(data): State<Arc<AppState>>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.comment_service.get_comments().await {
-        Ok(comments) => Ok((StatusCode::OK, Json(json!(comments)))),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({
-                "status": "error",
-                "message": "Failed to fetch comments",
-                "error": format!("{:?}", e)
-            })),
-        )),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let comments = data.di_container.comment_service.get_comments().await?;
+
+    Ok((StatusCode::OK, Json(json!(comments))))
 }
 
 #[utoipa::path(
@@ -47,7 +42,7 @@ pub async fn get_comments(
     path = "/api/comments/{id}",
     responses(
         (status = 200, description = "Get a comment", body = ApiResponse<CommentResponse>),
-        (status = 404, description = "Comment not found")
+        (status = 404, description = "Comment not found, or held for moderation and written by someone else", body = ProblemDetails)
     ),
     params(
         ("id" = i32, Path, description = "Comment ID")
@@ -56,26 +51,90 @@ pub async fn get_comments(
 )]
 pub async fn get_comment(
     State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     Path(comment_id): Path<i32>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.comment_service.get_comment(comment_id).await {
-        Ok(Some(comment)) => Ok((StatusCode::OK, Json(json!(comment)))),
-        Ok(None) => Err((
-            StatusCode::NOT_FOUND,
-            Json(json!({
-                "status": "fail",
-                "message": "Comment not found"
-            })),
-        )),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({
-                "status": "error",
-                "message": "Failed to fetch comment",
-                "error": format!("{:?}", e)
-            })),
-        )),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let comment = data
+        .di_container
+        .comment_service
+        .get_comment(&claims, comment_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(comment))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/posts/{id}/comments",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        FindPostCommentsRequest
+    ),
+    responses(
+        (status = 200, description = "One page of the post's approved comments; pass `pagination.next_cursor` as `cursor` for the next", body = ApiResponsePagination<Vec<CommentResponse>>),
+        (status = 404, description = "Post not found", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn get_post_comments(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+    ValidatedQuery(params): ValidatedQuery<FindPostCommentsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    data.di_container
+        .post_service
+        .get_post(Some(&claims), post_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Post not found".to_string()))?;
+
+    let comments = data
+        .di_container
+        .comment_service
+        .get_post_comments(post_id, &params)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(comments))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/posts/{id}/comments/tree",
+    responses(
+        (status = 200, description = "Approved top-level comments of the post with their approved replies nested below them", body = ApiResponse<Vec<CommentTreeResponse>>),
+        (status = 404, description = "Post not found", body = ProblemDetails)
+    ),
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn get_comment_tree(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    data.di_container
+        .post_service
+        .get_post(Some(&claims), post_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Post not found".to_string()))?;
+
+    let tree = data
+        .di_container
+        .comment_service
+        .get_comment_tree(post_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(tree))))
 }
 
 #[utoipa::path(
@@ -83,26 +142,25 @@ pub async fn get_comment(
     path = "/api/comments",
     request_body = CreateCommentRequest,
     responses(
-        (status = 201, description = "Comment created", body = ApiResponse<CommentResponse>),
-        (status = 400, description = "Invalid request body")
+        (status = 201, description = "Comment created; its `status` is `pending` while it waits for moderation", body = ApiResponse<CommentResponse>),
+        (status = 400, description = "Invalid request body", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 422, description = "Validation failed, the post does not exist, or the parent comment is missing, on another post or nested too deeply", body = ProblemDetails)
     ),
     tag = "comments"
 )]
 pub async fn create_comment(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<CreateCommentRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.comment_service.create_comment(&body).await {
-        Ok(comment) => Ok((StatusCode::CREATED, Json(json!(comment)))),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({
-                "status": "error",
-                "message": "Failed to create comment",
-                "error": format!("{:?}", e)
-            })),
-        )),
-    }
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<CreateCommentRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let comment = data
+        .di_container
+        .comment_service
+        .create_comment(&claims, &body)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(json!(comment))))
 }
 
 #[utoipa::path(
@@ -111,7 +169,9 @@ pub async fn create_comment(
     request_body = UpdateCommentRequest,
     responses(
         (status = 200, description = "Comment updated", body = ApiResponse<CommentResponse>),
-        (status = 404, description = "Comment not found")
+        (status = 404, description = "Comment not found", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     params(
         ("id" = i32, Path, description = "Comment ID")
@@ -120,34 +180,29 @@ pub async fn create_comment(
 )]
 pub async fn update_comment(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<UpdateCommentRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.comment_service.update_comment(&body).await {
-        Ok(Some(comment)) => Ok((StatusCode::OK, Json(json!(comment)))),
-        Ok(None) => Err((
-            StatusCode::NOT_FOUND,
-            Json(json!({
-                "status": "fail",
-                "message": "Comment not found"
-            })),
-        )),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({
-                "status": "error",
-                "message": "Failed to update comment",
-                "error": format!("{:?}", e)
-            })),
-        )),
-    }
+    Extension(claims): Extension<Claims>,
+    Path(comment_id): Path<i32>,
+    ValidatedJson(mut body): ValidatedJson<UpdateCommentRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    body.id = Some(comment_id);
+
+    let comment = data
+        .di_container
+        .comment_service
+        .update_comment(&claims, &body)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(comment))))
 }
 
 #[utoipa::path(
     delete,
     path = "/api/comments/{id}",
     responses(
-        (status = 200, description = "Comment deleted successfully", body=Value),
-        (status = 500, description = "Failed to delete comment")
+        (status = 200, description = "Comment and its replies deleted successfully", body=Value),
+        (status = 500, description = "Failed to delete comment", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
     ),
     params(
         ("id" = i32, Path, description = "Comment ID")
@@ -156,36 +211,138 @@ pub async fn update_comment(
 )]
 pub async fn delete_comment(
     State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     Path(comment_id): Path<i32>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.comment_service.delete_comment(comment_id).await {
-        Ok(_) => Ok((
-            StatusCode::OK,
-            Json(json!({
-                "status": "success",
-                "message": "Comment deleted successfully"
-            })),
-        )),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({
-                "status": "error",
-                "message": "Failed to delete comment",
-                "error": format!("{:?}", e)
-            })),
-        )),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    data.di_container
+        .comment_service
+        .delete_comment(&claims, comment_id)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "status": "success",
+            "message": "Comment deleted successfully"
+        })),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/comments/moderation",
+    params(FindModerationQueueRequest),
+    responses(
+        (status = 200, description = "Comments in the requested moderation state, oldest first", body = ApiResponsePagination<Vec<CommentResponse>>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn get_moderation_queue(
+    State(data): State<Arc<AppState>>,
+    ValidatedQuery(params): ValidatedQuery<FindModerationQueueRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let comments = data
+        .di_container
+        .comment_service
+        .get_moderation_queue(&params)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(comments))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/comments/moderation",
+    request_body = ModerateCommentsRequest,
+    responses(
+        (status = 200, description = "Comments moved to the requested state; ids that do not exist are skipped", body = ApiResponse<Vec<CommentResponse>>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 404, description = "None of the listed comments exist", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn moderate_comments(
+    State(data): State<Arc<AppState>>,
+    ValidatedJson(body): ValidatedJson<ModerateCommentsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let comments = data
+        .di_container
+        .comment_service
+        .moderate_comments(&body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(comments))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/posts/{id}/comments/settings",
+    request_body = UpdateCommentSettingsRequest,
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    responses(
+        (status = 200, description = "Comment settings updated", body = ApiResponse<CommentSettingsResponse>),
+        (status = 403, description = "Only the post's author or a moderator can change them", body = ProblemDetails),
+        (status = 404, description = "Post not found", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn update_comment_settings(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+    ValidatedJson(body): ValidatedJson<UpdateCommentSettingsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let settings = data
+        .di_container
+        .comment_service
+        .update_comment_settings(&claims, post_id, &body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(settings))))
 }
 
 pub fn comment_routes(app_state: Arc<AppState>) -> OpenApiRouter {
     let protected_routes = OpenApiRouter::new()
         .route("/api/comments", get(get_comments))
         .route("/api/comments/{id}", get(get_comment))
-        .route("/api/comments", post(create_comment))
+        .route("/api/posts/{id}/comments", get(get_post_comments))
+        .route("/api/posts/{id}/comments/tree", get(get_comment_tree))
+        .route(
+            "/api/comments",
+            post(create_comment).route_layer(middleware::from_fn_with_state(
+                Permission::WriteComments,
+                rbac::require_permission,
+            )),
+        )
         .route("/api/comments/{id}", put(update_comment))
         .route("/api/comments/{id}", delete(delete_comment))
+        .route("/api/posts/{id}/comments/settings", put(update_comment_settings))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
+    let moderation_routes = OpenApiRouter::new()
+        .route("/api/comments/moderation", get(get_moderation_queue))
+        .route("/api/comments/moderation", post(moderate_comments))
+        .route_layer(middleware::from_fn_with_state(
+            Permission::ModerateComments,
+            rbac::require_permission,
+        ))
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone());
 
-        OpenApiRouter::new().merge(protected_routes).with_state(app_state)
+        OpenApiRouter::new().merge(protected_routes).merge(moderation_routes).with_state(app_state)
 }
\ No newline at end of file
@@ -0,0 +1,239 @@
+use std::{fs::Metadata, sync::Arc, time::UNIX_EPOCH};
+
+use axum::{
+    body::Body,
+    extract::{Path, Request, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware,
+    response::{IntoResponse, Redirect, Response},
+    routing::{delete, get},
+    Extension, Json,
+};
+use serde_json::json;
+use tower_http::services::ServeFile;
+use utoipa_axum::router::OpenApiRouter;
+
+use crate::{
+    config::Claims,
+    domain::{
+        ApiResponse, ApiResponsePagination, FindAllMediaRequest, MediaResponse, ProblemDetails,
+    },
+    extractor::ValidatedQuery,
+    middleware::{jwt, rbac},
+    state::AppState,
+    utils::{AppError, Permission},
+};
+
+/// Stored files never change once written (every upload gets a fresh key),
+/// so clients may cache them for good.
+const CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+#[utoipa::path(
+    get,
+    path = "/media/{date}/{file}",
+    params(
+        ("date" = String, Path, description = "Upload date folder, e.g. 2025-01-31"),
+        ("file" = String, Path, description = "Stored file name")
+    ),
+    responses(
+        (status = 200, description = "File contents", content_type = "application/octet-stream"),
+        (status = 206, description = "Requested byte range", content_type = "application/octet-stream"),
+        (status = 304, description = "Cached copy is still current"),
+        (status = 307, description = "Redirect to a presigned object storage URL"),
+        (status = 400, description = "Invalid file path", body = ProblemDetails),
+        (status = 404, description = "File not found", body = ProblemDetails),
+        (status = 416, description = "Range not satisfiable")
+    ),
+    tag = "media"
+)]
+pub async fn get_media(
+    State(data): State<Arc<AppState>>,
+    Path((date, file)): Path<(String, String)>,
+    request: Request,
+) -> Result<Response, AppError> {
+    let key = format!("{}/{}", date, file);
+    let storage = &data.di_container.storage;
+
+    let Some(path) = storage.local_path(&key)? else {
+        let url = storage.url(&key).await?;
+        return Ok(Redirect::temporary(&url).into_response());
+    };
+
+    let metadata = match tokio::fs::metadata(&path).await {
+        Ok(metadata) if metadata.is_file() => metadata,
+        _ => return Err(AppError::NotFound("File not found".to_string())),
+    };
+
+    let etag = entity_tag(&metadata);
+    let (mut parts, body) = request.into_parts();
+
+    if let Some(if_none_match) = parts.headers.get(header::IF_NONE_MATCH) {
+        if etag_matches(if_none_match, &etag) {
+            let mut response = StatusCode::NOT_MODIFIED.into_response();
+            set_cache_headers(response.headers_mut(), &etag);
+            return Ok(response);
+        }
+
+        // If-None-Match takes precedence over If-Modified-Since (RFC 9110, 13.2.2).
+        parts.headers.remove(header::IF_MODIFIED_SINCE);
+    }
+
+    // A range is only valid against the representation the client already has.
+    if let Some(if_range) = parts.headers.remove(header::IF_RANGE) {
+        if if_range.as_bytes() != etag.as_bytes() {
+            parts.headers.remove(header::RANGE);
+        }
+    }
+
+    let response = ServeFile::new(&path)
+        .try_call(Request::from_parts(parts, body))
+        .await
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => AppError::NotFound("File not found".to_string()),
+            _ => AppError::StorageError(format!("Failed to read file: {}", e)),
+        })?;
+
+    let mut response = response.map(Body::new);
+    if response.status().is_success() || response.status() == StatusCode::NOT_MODIFIED {
+        set_cache_headers(response.headers_mut(), &etag);
+    }
+
+    Ok(response)
+}
+
+/// Size and modification time identify a stored file's bytes, which are
+/// never rewritten in place, so the tag can be strong.
+fn entity_tag(metadata: &Metadata) -> String {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|since| since.as_nanos())
+        .unwrap_or_default();
+
+    format!("\"{:x}-{:x}\"", metadata.len(), modified)
+}
+
+fn etag_matches(header: &HeaderValue, etag: &str) -> bool {
+    let Ok(value) = header.to_str() else {
+        return false;
+    };
+
+    value
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+fn set_cache_headers(headers: &mut HeaderMap, etag: &str) {
+    if let Ok(value) = HeaderValue::from_str(etag) {
+        headers.insert(header::ETAG, value);
+    }
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(CACHE_CONTROL),
+    );
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/media",
+    params(FindAllMediaRequest),
+    responses(
+        (status = 200, description = "List media library entries, newest first", body = ApiResponsePagination<Vec<MediaResponse>>),
+        (status = 422, description = "Request validation failed", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "media"
+)]
+pub async fn get_media_list(
+    State(data): State<Arc<AppState>>,
+    ValidatedQuery(params): ValidatedQuery<FindAllMediaRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let media = data
+        .di_container
+        .media_service
+        .get_media_list(params)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(media))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/media/{id}",
+    params(
+        ("id" = i32, Path, description = "Media ID")
+    ),
+    responses(
+        (status = 200, description = "Media details", body = ApiResponse<MediaResponse>),
+        (status = 404, description = "Media not found", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "media"
+)]
+pub async fn get_media_entry(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let media = data.di_container.media_service.get_media(id).await?;
+
+    Ok((StatusCode::OK, Json(json!(media))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/media/{id}",
+    params(
+        ("id" = i32, Path, description = "Media ID")
+    ),
+    responses(
+        (status = 200, description = "Media and its files deleted", body = Value),
+        (status = 404, description = "Media not found", body = ProblemDetails),
+        (status = 409, description = "Media is still used by a post or revision", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "media"
+)]
+pub async fn delete_media(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let response = data
+        .di_container
+        .media_service
+        .delete_media(&claims, id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+pub fn media_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    let protected_routes = OpenApiRouter::new()
+        .route("/api/media", get(get_media_list))
+        .route("/api/media/{id}", get(get_media_entry))
+        .route("/api/media/{id}", delete(delete_media))
+        .route_layer(middleware::from_fn_with_state(
+            Permission::WritePosts,
+            rbac::require_permission,
+        ))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
+    let public_routes = OpenApiRouter::new().route("/media/{date}/{file}", get(get_media));
+
+    OpenApiRouter::new()
+        .merge(protected_routes)
+        .merge(public_routes)
+        .with_state(app_state)
+}
@@ -1,26 +1,36 @@
+mod admin;
 mod auth;
 mod category;
 mod comments;
+mod media;
+mod post_revisions;
 mod posts;
+mod tag;
 mod user;
 
 use std::sync::Arc;
 
 use axum::extract::DefaultBodyLimit;
 use tokio::net::TcpListener;
-use tower_http::{limit::RequestBodyLimitLayer, services::ServeDir};
-use tracing_subscriber::fmt::layer;
+use tower_http::limit::RequestBodyLimitLayer;
 use utoipa::openapi::security::SecurityScheme;
 use utoipa::{Modify, OpenApi};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::state::AppState;
+use crate::{
+    service::{spawn_publish_scheduler, spawn_upload_gc},
+    state::AppState,
+};
 
+pub use self::admin::admin_routes;
 pub use self::auth::auth_routes;
 pub use self::category::category_routes;
 pub use self::comments::comment_routes;
+pub use self::media::media_routes;
+pub use self::post_revisions::post_revision_routes;
 pub use self::posts::post_routes;
+pub use self::tag::tag_routes;
 pub use self::user::user_routes;
 
 #[derive(OpenApi)]
@@ -29,34 +39,77 @@ pub use self::user::user_routes;
         auth::login_user_handler,
         auth::get_me_handler,
         auth::register_user_handler,
+        auth::refresh_token_handler,
+        auth::logout_handler,
         user::create_user,
         user::find_user_by_email,
         user::update_user,
+        user::update_user_role,
         user::delete_user,
         category::get_categories,
+        category::get_category_tree,
+        category::get_category_by_slug,
         category::get_category,
         category::create_category,
         category::update_category,
         category::delete_category,
+        category::merge_category,
         comments::get_comments,
         comments::get_comment,
+        comments::get_post_comments,
+        comments::get_comment_tree,
         comments::create_comment,
         comments::update_comment,
         comments::delete_comment,
+        comments::get_moderation_queue,
+        comments::moderate_comments,
+        comments::update_comment_settings,
         posts::get_posts,
+        posts::get_posts_for_user,
         posts::get_post,
+        posts::get_post_by_slug,
         posts::get_post_relation,
         posts::create_post,
         posts::update_post,
+        posts::submit_post,
+        posts::publish_post,
+        posts::reject_post,
+        posts::schedule_post,
+        posts::archive_post,
+        posts::restore_post,
         posts::delete_post,
+        post_revisions::get_post_revisions,
+        post_revisions::diff_post_revisions,
+        post_revisions::get_post_revision,
+        post_revisions::restore_post_revision,
+        tag::get_tags,
+        tag::get_tag,
+        tag::create_tag,
+        tag::update_tag,
+        tag::delete_tag,
+        admin::get_scheduler_status,
+        admin::get_upload_gc_status,
+        admin::run_upload_gc,
+        admin::get_comment_filter_stats,
+        admin::get_comment_filter_rules,
+        admin::create_comment_filter_rule,
+        admin::update_comment_filter_rule,
+        admin::delete_comment_filter_rule,
+        media::get_media,
+        media::get_media_list,
+        media::get_media_entry,
+        media::delete_media,
     ),
     modifiers(&SecurityAddon),
     tags(
         (name = "auth", description = "Authentication endpoints."),
         (name = "category", description = "Category management endpoints."),
         (name = "posts", description = "Post management endpoints."),
+        (name = "tags", description = "Tag management endpoints."),
         (name = "comments", description = "Comments management endpoints."),
-        (name = "users", description = "User management endpoints.")
+        (name = "users", description = "User management endpoints."),
+        (name = "admin", description = "Administration endpoints."),
+        (name = "media", description = "Uploaded file delivery and media library.")
     )
 )]
 struct ApiDoc;
@@ -82,12 +135,19 @@ impl AppRouter {
     pub async fn serve(port: u16, app_state: AppState) -> Result<(), Box<dyn std::error::Error>> {
         let shared_state = Arc::new(app_state);
 
+        spawn_publish_scheduler(shared_state.di_container.publish_scheduler.clone());
+        spawn_upload_gc(shared_state.di_container.upload_gc.clone());
+
         let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
             .merge(auth_routes(shared_state.clone()))
             .merge(category_routes(shared_state.clone()))
             .merge(comment_routes(shared_state.clone()))
             .merge(post_routes(shared_state.clone()))
+            .merge(post_revision_routes(shared_state.clone()))
+            .merge(tag_routes(shared_state.clone()))
             .merge(user_routes(shared_state.clone()))
+            .merge(admin_routes(shared_state.clone()))
+            .merge(media_routes(shared_state.clone()))
             .layer(DefaultBodyLimit::disable())
             .layer(RequestBodyLimitLayer::new(250 * 1024 * 1024))
             .split_for_parts();
@@ -0,0 +1,163 @@
+use crate::{
+    config::Claims,
+    domain::{
+        ApiResponse, PostResponse, PostRevisionResponse, ProblemDetails, RevisionDiffRequest,
+        RevisionDiffResponse,
+    },
+    extractor::ValidatedQuery,
+    middleware::{jwt, rbac},
+    state::AppState,
+    utils::{AppError, Permission},
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Extension, Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/posts/{id}/revisions",
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    responses(
+        (status = 200, description = "Revisions of the post, newest first", body = ApiResponse<Vec<PostRevisionResponse>>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 404, description = "Post not found", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn get_post_revisions(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let revisions = data
+        .di_container
+        .post_service
+        .get_post_revisions(&claims, post_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(revisions))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/posts/{id}/revisions/diff",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        RevisionDiffRequest
+    ),
+    responses(
+        (status = 200, description = "Line-level diff of the body between two revisions", body = ApiResponse<RevisionDiffResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 404, description = "Revision not found", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn diff_post_revisions(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+    ValidatedQuery(params): ValidatedQuery<RevisionDiffRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let diff = data
+        .di_container
+        .post_service
+        .diff_post_revisions(&claims, post_id, params.from, params.to)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(diff))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/posts/{id}/revisions/{revision}",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        ("revision" = i32, Path, description = "Revision number")
+    ),
+    responses(
+        (status = 200, description = "Post revision", body = ApiResponse<PostRevisionResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 404, description = "Revision not found", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn get_post_revision(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path((post_id, revision)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let revision = data
+        .di_container
+        .post_service
+        .get_post_revision(&claims, post_id, revision)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(revision))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/posts/{id}/revisions/{revision}/restore",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        ("revision" = i32, Path, description = "Revision number to restore")
+    ),
+    responses(
+        (status = 200, description = "Revision restored as the newest revision", body = ApiResponse<PostResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 404, description = "Revision not found", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn restore_post_revision(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path((post_id, revision)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let post = data
+        .di_container
+        .post_service
+        .restore_post_revision(&claims, post_id, revision)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(post))))
+}
+
+pub fn post_revision_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/posts/{id}/revisions", get(get_post_revisions))
+        .route("/api/posts/{id}/revisions/diff", get(diff_post_revisions))
+        .route("/api/posts/{id}/revisions/{revision}", get(get_post_revision))
+        .route(
+            "/api/posts/{id}/revisions/{revision}/restore",
+            post(restore_post_revision).route_layer(middleware::from_fn_with_state(
+                Permission::WritePosts,
+                rbac::require_permission,
+            )),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state)
+}
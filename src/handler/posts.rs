@@ -1,41 +1,73 @@
 use crate::{
+    config::Claims,
     domain::{
-        ApiResponse, ApiResponsePagination, CreatePostRequest, FindAllPostRequest,
-        PostRelationResponse, PostResponse, UpdatePostRequest,
+        ApiResponse, ApiResponsePagination, CreatePostRequest, FindAllPostRequest, PostForm,
+        PostRelationResponse, PostResponse, PostSlugLookup, PostTransition, ProblemDetails,
+        SchedulePostRequest, UpdatePostRequest, UploadResponse, UploadedFile,
     },
-    middleware::jwt,
+    extractor::{ValidatedJson, ValidatedMultipart, ValidatedQuery},
+    middleware::{jwt, rbac},
     state::AppState,
+    utils::{AppError, Permission},
 };
 use axum::{
-    extract::{Multipart, Path, Query, State},
-    http::StatusCode,
+    extract::{Path, State},
+    http::{header, StatusCode},
     middleware,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     routing::{delete, get, post, put},
-    Json,
+    Extension, Json,
 };
 use serde_json::json;
 use std::sync::Arc;
 use utoipa_axum::router::OpenApiRouter;
 
+#[utoipa::path(
+    get,
+    path = "/posts",
+    params(FindAllPostRequest),
+    responses(
+        (status = 200, description = "List published posts", body = ApiResponsePagination<Vec<PostResponse>>),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    tag = "posts"
+)]
+pub async fn get_posts(
+    State(data): State<Arc<AppState>>,
+    ValidatedQuery(params): ValidatedQuery<FindAllPostRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let posts = data
+        .di_container
+        .post_service
+        .get_all_posts(None, params)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(posts))))
+}
+
 #[utoipa::path(
     get,
     path = "/api/posts",
     params(FindAllPostRequest),
     responses(
-        (status = 200, description = "List all posts successfully", body = ApiResponsePagination<Vec<PostResponse>>)
+        (status = 200, description = "List posts visible to the caller, including drafts", body = ApiResponsePagination<Vec<PostResponse>>),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     security(("bearer_auth" = [])),
-    tag = "post"
+    tag = "posts"
 )]
-pub async fn get_posts(
+pub async fn get_posts_for_user(
     State(data): State<Arc<AppState>>,
-    Query(params): Query<FindAllPostRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.post_service.get_all_posts(params).await {
-        Ok(posts) => Ok((StatusCode::OK, Json(json!(posts)))),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+    Extension(claims): Extension<Claims>,
+    ValidatedQuery(params): ValidatedQuery<FindAllPostRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let posts = data
+        .di_container
+        .post_service
+        .get_all_posts(Some(&claims), params)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(posts))))
 }
 
 #[utoipa::path(
@@ -46,24 +78,65 @@ pub async fn get_posts(
     ),
     responses(
         (status = 200, description = "Get post by ID", body = ApiResponse<PostResponse>),
-        (status = 404, description = "Post not found")
+        (status = 404, description = "Post not found", body = ProblemDetails)
     ),
     tag = "posts"
 )]
 pub async fn get_post(
     State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     Path(post_id): Path<i32>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.post_service.get_post(post_id).await {
-        Ok(Some(post)) => Ok((StatusCode::OK, Json(json!(post)))),
-        Ok(None) => Err((
-            StatusCode::NOT_FOUND,
-            Json(json!({
-                "status": "fail",
-                "message": "Post not found"
-            })),
-        )),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+) -> Result<impl IntoResponse, AppError> {
+    let post = data
+        .di_container
+        .post_service
+        .get_post(Some(&claims), post_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Post not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(post))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/posts/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "Post slug")
+    ),
+    responses(
+        (status = 200, description = "Get post by slug", body = ApiResponse<PostResponse>),
+        (status = 301, description = "The slug changed; Location points to the current one"),
+        (status = 404, description = "Post not found", body = ProblemDetails)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "posts"
+)]
+pub async fn get_post_by_slug(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(slug): Path<String>,
+) -> Result<Response, AppError> {
+    match data
+        .di_container
+        .post_service
+        .get_post_by_slug(Some(&claims), &slug)
+        .await?
+    {
+        PostSlugLookup::Found(post) => Ok((StatusCode::OK, Json(json!(post))).into_response()),
+        PostSlugLookup::Moved(current) => {
+            let location = format!("/api/posts/by-slug/{}", current);
+
+            Ok((
+                StatusCode::MOVED_PERMANENTLY,
+                [(header::LOCATION, location.clone())],
+                Json(json!({
+                    "status": "success",
+                    "message": "Post moved",
+                    "location": location
+                })),
+            )
+                .into_response())
+        }
     }
 }
 
@@ -75,23 +148,22 @@ pub async fn get_post(
     ),
     responses(
         (status = 200, description = "Get related posts", body = ApiResponse<Vec<PostRelationResponse>>),
-        (status = 404, description = "Post not found")
+        (status = 404, description = "Post not found", body = ProblemDetails)
     ),
     tag = "posts"
 )]
 pub async fn get_post_relation(
     State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     Path(post_id): Path<i32>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data
+) -> Result<impl IntoResponse, AppError> {
+    let posts = data
         .di_container
         .post_service
-        .get_post_relation(post_id)
-        .await
-    {
-        Ok(posts) => Ok((StatusCode::OK, Json(json!(posts)))),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+        .get_post_relation(Some(&claims), post_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(posts))))
 }
 
 #[utoipa::path(
@@ -100,8 +172,12 @@ pub async fn get_post_relation(
     request_body(content = CreatePostRequest, content_type = "multipart/form-data"),
     responses(
         (status = 201, description = "Post created successfully", body = ApiResponse<PostResponse>),
-        (status = 400, description = "Invalid request body"),
-        (status = 500, description = "Internal server error")
+        (status = 400, description = "Missing or malformed form field", body = ProblemDetails),
+        (status = 413, description = "A form field exceeds its size limit", body = ProblemDetails),
+        (status = 415, description = "File is not a JPEG, PNG or WebP image", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 422, description = "Validation failed, or the image is corrupt or exceeds the maximum dimensions", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -110,109 +186,30 @@ pub async fn get_post_relation(
 )]
 pub async fn create_post(
     State(data): State<Arc<AppState>>,
-    mut multipart: Multipart,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    let mut title: Option<String> = None;
-    let mut body: Option<String> = None;
-    let mut category_id: Option<i32> = None;
-    let mut user_id: Option<i32> = None;
-    let mut user_name: Option<String> = None;
-    let mut file_data: Option<(String, String, Vec<u8>)> = None;
-
-    while let Some(field) = multipart.next_field().await.unwrap() {
-        match field.name() {
-            Some("title") => {
-                title = Some(field.text().await.expect("should be text for title field"));
-            }
-            Some("body") => {
-                body = Some(field.text().await.expect("should be text for body field"));
-            }
-            Some("category_id") => {
-                category_id = Some(
-                    field
-                        .text()
-                        .await
-                        .expect("should be text for category_id field")
-                        .parse()
-                        .expect("should be a number for category_id field"),
-                );
-            }
-            Some("user_id") => {
-                user_id = Some(
-                    field
-                        .text()
-                        .await
-                        .expect("should be text for user_id field")
-                        .parse()
-                        .expect("should be a number for user_id field"),
-                );
-            }
-            Some("user_name") => {
-                user_name = Some(
-                    field
-                        .text()
-                        .await
-                        .expect("should be text for user_name field"),
-                );
-            }
-            Some("file") => {
-                let file_name = field.file_name().map(ToString::to_string);
-                let content_type = field.content_type().map(ToString::to_string);
-                let bytes = field.bytes().await.expect("should be bytes for file field");
-
-                if let (Some(name), Some(content_type)) = (file_name, content_type) {
-                    if !bytes.is_empty() {
-                        file_data = Some((name, content_type, bytes.to_vec()));
-                    }
-                }
-            }
-            _ => (),
-        };
-    }
-
-    let uploaded_file_name = if let Some((name, content_type, file_bytes)) = file_data {
-        let upload_result = data
-            .di_container
-            .file_service
-            .upload_image("posts", name, content_type, file_bytes)
-            .await;
-
-        match upload_result {
-            Ok(response) => response.file_name.clone(),
-            Err((status, response)) => {
-                return Err((
-                    status,
-                    Json(json!({
-                        "error": response.message
-                    })),
-                ))
-            }
-        }
-    } else {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(json!({
-                "error": "File is required"
-            })),
-        ));
+    Extension(claims): Extension<Claims>,
+    ValidatedMultipart(form): ValidatedMultipart<PostForm>,
+) -> Result<impl IntoResponse, AppError> {
+    let uploaded = match form.file {
+        Some(file) => upload_post_image(&data, &claims, file).await?,
+        None => return Err(AppError::BadRequest("File is required".to_string())),
     };
 
     let post_data = CreatePostRequest {
-        title: title.unwrap_or_default(),
-        body: body.unwrap_or_default(),
-        file: uploaded_file_name,
-        category_id: category_id.unwrap_or(0),
-        user_id: user_id.unwrap_or(0),
-        user_name: user_name.unwrap_or_default(),
+        title: form.title,
+        body: form.body,
+        file: uploaded.file_name.clone(),
+        media_id: Some(uploaded.media_id),
+        category_id: form.category_id,
+        tag_ids: form.tag_ids.unwrap_or_default(),
     };
 
-    match data.di_container.post_service.create_post(&post_data).await {
-        Ok(post) => Ok((StatusCode::CREATED, Json(json!(post)))),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({"error": e.to_string()})),
-        )),
+    let result = data.di_container.post_service.create_post(&claims, &post_data).await;
+
+    if result.is_err() {
+        discard_post_image(&data, &uploaded).await;
     }
+
+    Ok((StatusCode::CREATED, Json(json!(result?))))
 }
 
 #[utoipa::path(
@@ -224,8 +221,12 @@ pub async fn create_post(
     request_body(content = UpdatePostRequest, content_type = "multipart/form-data"),
     responses(
         (status = 200, description = "Post updated successfully", body = ApiResponse<PostResponse>),
-        (status = 400, description = "Invalid request body"),
-        (status = 404, description = "Post not found")
+        (status = 400, description = "Missing or malformed form field", body = ProblemDetails),
+        (status = 413, description = "A form field exceeds its size limit", body = ProblemDetails),
+        (status = 415, description = "File is not a JPEG, PNG or WebP image", body = ProblemDetails),
+        (status = 404, description = "Post not found", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 422, description = "Validation failed, or the image is corrupt or exceeds the maximum dimensions", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -234,124 +235,235 @@ pub async fn create_post(
 )]
 pub async fn update_post(
     State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     Path(post_id): Path<i32>,
-    mut multipart: Multipart,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    let mut title: Option<String> = None;
-    let mut body: Option<String> = None;
-    let mut category_id: Option<i32> = None;
-    let mut user_id: Option<i32> = None;
-    let mut user_name: Option<String> = None;
-    let mut file_data: Option<(String, String, Vec<u8>)> = None;
-
-    let old_post = match data.di_container.post_service.get_post(post_id).await {
-        Ok(post) => post.unwrap().data,
-        Err(e) => return Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    ValidatedMultipart(form): ValidatedMultipart<PostForm>,
+) -> Result<impl IntoResponse, AppError> {
+    data.di_container
+        .post_service
+        .get_post(Some(&claims), post_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Post not found".to_string()))?;
+
+    let uploaded = match form.file {
+        Some(file) => Some(upload_post_image(&data, &claims, file).await?),
+        None => None,
     };
 
-    if !old_post.img.is_empty() {
-        let _ = data
-            .di_container
-            .file_service
-            .delete_image("posts", &old_post.img)
-            .await;
-    }
+    let post_data = UpdatePostRequest {
+        post_id: Some(post_id),
+        title: form.title,
+        body: form.body,
+        file: uploaded.as_ref().map(|uploaded| uploaded.file_name.clone()),
+        media_id: uploaded.as_ref().map(|uploaded| uploaded.media_id),
+        category_id: form.category_id,
+        tag_ids: form.tag_ids,
+    };
 
-    while let Some(field) = multipart.next_field().await.unwrap() {
-        match field.name() {
-            Some("title") => {
-                title = Some(field.text().await.expect("should be text for title field"));
-            }
-            Some("body") => {
-                body = Some(field.text().await.expect("should be text for body field"));
-            }
-            Some("category_id") => {
-                category_id = Some(
-                    field
-                        .text()
-                        .await
-                        .expect("should be text for category_id field")
-                        .parse()
-                        .expect("should be a number for category_id field"),
-                );
-            }
-            Some("user_id") => {
-                user_id = Some(
-                    field
-                        .text()
-                        .await
-                        .expect("should be text for user_id field")
-                        .parse()
-                        .expect("should be a number for user_id field"),
-                );
-            }
-            Some("user_name") => {
-                user_name = Some(
-                    field
-                        .text()
-                        .await
-                        .expect("should be text for user_name field"),
-                );
-            }
-            Some("file") => {
-                let file_name = field.file_name().map(ToString::to_string);
-                let content_type = field.content_type().map(ToString::to_string);
-                let bytes = field.bytes().await.expect("should be bytes for file field");
-
-                if let (Some(name), Some(content_type)) = (file_name, content_type) {
-                    if !bytes.is_empty() {
-                        file_data = Some((name, content_type, bytes.to_vec()));
-                    }
-                }
-            }
-            _ => (),
-        };
+    let result = data
+        .di_container
+        .post_service
+        .update_post(&claims, &post_data)
+        .await;
+
+    // The previous image stays in the media library because older revisions still
+    // point at it; only a failed update leaves the new upload unreferenced.
+    if let (Err(_), Some(uploaded)) = (&result, &uploaded) {
+        discard_post_image(&data, uploaded).await;
     }
 
-    let uploaded_file_name = if let Some((name, content_type, file_bytes)) = file_data {
-        let upload_result = data
+    Ok((StatusCode::OK, Json(json!(result?))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/posts/{id}/submit",
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    responses(
+        (status = 200, description = "Post submitted for review", body = ApiResponse<PostResponse>),
+        (status = 409, description = "Transition not allowed from the current status", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn submit_post(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    change_post_status(&data, &claims, post_id, PostTransition::Submit).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/posts/{id}/publish",
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    responses(
+        (status = 200, description = "Post published", body = ApiResponse<PostResponse>),
+        (status = 409, description = "Transition not allowed from the current status", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn publish_post(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    change_post_status(&data, &claims, post_id, PostTransition::Publish).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/posts/{id}/reject",
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    responses(
+        (status = 200, description = "Post returned to draft", body = ApiResponse<PostResponse>),
+        (status = 409, description = "Transition not allowed from the current status", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn reject_post(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    change_post_status(&data, &claims, post_id, PostTransition::Reject).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/posts/{id}/archive",
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    responses(
+        (status = 200, description = "Post archived", body = ApiResponse<PostResponse>),
+        (status = 409, description = "Transition not allowed from the current status", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn archive_post(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    change_post_status(&data, &claims, post_id, PostTransition::Archive).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/posts/{id}/restore",
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    responses(
+        (status = 200, description = "Post restored to draft", body = ApiResponse<PostResponse>),
+        (status = 409, description = "Transition not allowed from the current status", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn restore_post(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    change_post_status(&data, &claims, post_id, PostTransition::Restore).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/posts/{id}/schedule",
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    request_body = SchedulePostRequest,
+    responses(
+        (status = 200, description = "Post scheduled for publishing", body = ApiResponse<PostResponse>),
+        (status = 409, description = "Post cannot be scheduled from its current status", body = ProblemDetails),
+        (status = 422, description = "publish_at is not in the future", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posts"
+)]
+pub async fn schedule_post(
+    State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(post_id): Path<i32>,
+    ValidatedJson(body): ValidatedJson<SchedulePostRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let post = data
+        .di_container
+        .post_service
+        .schedule_post(&claims, post_id, &body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!(post))))
+}
+
+async fn upload_post_image(
+    data: &AppState,
+    claims: &Claims,
+    file: UploadedFile,
+) -> Result<UploadResponse, AppError> {
+    data.di_container
+        .file_service
+        .upload_image(file, claims.user_id as i32)
+        .await
+}
+
+/// Removes an upload whose post could not be saved, unless it was an existing
+/// media entry that other posts may already use.
+async fn discard_post_image(data: &AppState, uploaded: &UploadResponse) {
+    if !uploaded.deduplicated {
+        let _ = data
             .di_container
             .file_service
-            .upload_image("posts", name, content_type, file_bytes)
+            .delete_media(uploaded.media_id)
             .await;
+    }
+}
 
-        match upload_result {
-            Ok(response) => response.file_name.clone(),
-            Err((status, response)) => {
-                return Err((
-                    status,
-                    Json(json!({
-                        "error": response.message
-                    })),
-                ))
-            }
-        }
-    } else {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(json!({
-                "error": "File is required"
-            })),
-        ));
-    };
-
-    let post_data = UpdatePostRequest {
-        post_id: Some(post_id),
-        title: title.unwrap_or_default(),
-        body: body.unwrap_or_default(),
-        file: uploaded_file_name,
-        category_id: category_id.unwrap_or(0),
-        user_id: user_id.unwrap_or(0),
-        user_name: user_name.unwrap_or_default(),
-    };
+async fn change_post_status(
+    data: &AppState,
+    claims: &Claims,
+    post_id: i32,
+    transition: PostTransition,
+) -> Result<(StatusCode, Json<serde_json::Value>), AppError> {
+    let post = data
+        .di_container
+        .post_service
+        .transition_post(claims, post_id, transition)
+        .await?;
 
-    match data.di_container.post_service.update_post(&post_data).await {
-        Ok(post) => Ok((StatusCode::OK, Json(json!(post)))),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({"error": e.to_string()})),
-        )),
-    }
+    Ok((StatusCode::OK, Json(json!(post))))
 }
 
 #[utoipa::path(
@@ -362,8 +474,9 @@ pub async fn update_post(
     ),
     responses(
         (status = 200, description = "Post deleted successfully"),
-        (status = 404, description = "Post not found"),
-        (status = 500, description = "Internal server error")
+        (status = 404, description = "Post not found", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -372,27 +485,74 @@ pub async fn update_post(
 )]
 pub async fn delete_post(
     State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     Path(post_id): Path<i32>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.post_service.delete_post(post_id).await {
-        Ok(_) => Ok((
-            StatusCode::OK,
-            Json(json!({
-                "status": "success",
-                "message": "Post deleted successfully"
-            })),
-        )),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    data.di_container
+        .post_service
+        .delete_post(&claims, post_id)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "status": "success",
+            "message": "Post deleted successfully"
+        })),
+    ))
 }
 
 pub fn post_routes(app_state: Arc<AppState>) -> OpenApiRouter {
     let protected_routes = OpenApiRouter::new()
-        .route("/api/posts", post(create_post))
+        .route(
+            "/api/posts",
+            post(create_post).route_layer(middleware::from_fn_with_state(
+                Permission::WritePosts,
+                rbac::require_permission,
+            )),
+        )
+        .route("/api/posts", get(get_posts_for_user))
         .route("/api/posts/{id}", get(get_post))
-        .route("/api/posts/{id}", put(update_post))
-        .route("/api/posts/{id}", delete(delete_post))
+        .route("/api/posts/by-slug/{slug}", get(get_post_by_slug))
+        .route(
+            "/api/posts/{id}",
+            put(update_post).route_layer(middleware::from_fn_with_state(
+                Permission::WritePosts,
+                rbac::require_permission,
+            )),
+        )
+        .route(
+            "/api/posts/{id}",
+            delete(delete_post).route_layer(middleware::from_fn_with_state(
+                Permission::WritePosts,
+                rbac::require_permission,
+            )),
+        )
         .route("/api/posts/{id}/relation", get(get_post_relation))
+        .route("/api/posts/{id}/submit", post(submit_post))
+        .route(
+            "/api/posts/{id}/publish",
+            post(publish_post).route_layer(middleware::from_fn_with_state(
+                Permission::PublishPosts,
+                rbac::require_permission,
+            )),
+        )
+        .route(
+            "/api/posts/{id}/reject",
+            post(reject_post).route_layer(middleware::from_fn_with_state(
+                Permission::PublishPosts,
+                rbac::require_permission,
+            )),
+        )
+        .route(
+            "/api/posts/{id}/schedule",
+            post(schedule_post).route_layer(middleware::from_fn_with_state(
+                Permission::PublishPosts,
+                rbac::require_permission,
+            )),
+        )
+        .route("/api/posts/{id}/archive", post(archive_post))
+        .route("/api/posts/{id}/restore", post(restore_post))
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone());
 
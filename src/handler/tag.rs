@@ -0,0 +1,161 @@
+use crate::{
+    domain::{
+        ApiResponse, ApiResponsePagination, CreateTagRequest, FindAllTagRequest, ProblemDetails,
+        TagResponse, UpdateTagRequest,
+    },
+    extractor::{ValidatedJson, ValidatedQuery},
+    middleware::{jwt, rbac},
+    state::AppState,
+    utils::{AppError, Permission},
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/tags",
+    params(FindAllTagRequest),
+    responses(
+        (status = 200, description = "List all tags with their published post counts", body = ApiResponsePagination<Vec<TagResponse>>),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    tag = "tags"
+)]
+pub async fn get_tags(
+    State(data): State<Arc<AppState>>,
+    ValidatedQuery(params): ValidatedQuery<FindAllTagRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let tags = data.di_container.tag_service.get_tags(params).await?;
+
+    Ok((StatusCode::OK, Json(json!(tags))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/tags/{id}",
+    params(
+        ("id" = i32, Path, description = "Tag ID")
+    ),
+    responses(
+        (status = 200, description = "Tag details", body = ApiResponse<TagResponse>),
+        (status = 404, description = "Tag not found", body = ProblemDetails)
+    ),
+    tag = "tags"
+)]
+pub async fn get_tag(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let tag = data.di_container.tag_service.get_tag(id).await?;
+
+    Ok((StatusCode::OK, Json(json!(tag))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tags",
+    request_body = CreateTagRequest,
+    responses(
+        (status = 201, description = "Create tag", body = ApiResponse<TagResponse>),
+        (status = 409, description = "Tag name already exists", body = ProblemDetails),
+        (status = 422, description = "Tag name is empty", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tags"
+)]
+pub async fn create_tag(
+    State(data): State<Arc<AppState>>,
+    ValidatedJson(body): ValidatedJson<CreateTagRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let tag = data.di_container.tag_service.create_tag(&body).await?;
+
+    Ok((StatusCode::CREATED, Json(json!(tag))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/tags/{id}",
+    params(
+        ("id" = i32, Path, description = "Tag ID")
+    ),
+    request_body = UpdateTagRequest,
+    responses(
+        (status = 200, description = "Update tag", body = ApiResponse<TagResponse>),
+        (status = 409, description = "Tag name already exists", body = ProblemDetails),
+        (status = 422, description = "Tag name is empty", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tags"
+)]
+pub async fn update_tag(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    ValidatedJson(mut body): ValidatedJson<UpdateTagRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    body.id = Some(id);
+
+    let tag = data.di_container.tag_service.update_tag(&body).await?;
+
+    Ok((StatusCode::OK, Json(json!(tag))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/tags/{id}",
+    params(
+        ("id" = i32, Path, description = "Tag ID")
+    ),
+    responses(
+        (status = 200, description = "Delete tag", body = Value),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tags"
+)]
+pub async fn delete_tag(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let response = data.di_container.tag_service.delete_tag(id).await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+pub fn tag_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    let protected_routes = OpenApiRouter::new()
+        .route("/api/tags", post(create_tag))
+        .route("/api/tags/{id}", put(update_tag))
+        .route("/api/tags/{id}", delete(delete_tag))
+        .route_layer(middleware::from_fn_with_state(
+            Permission::ManageTags,
+            rbac::require_permission,
+        ))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
+    let public_routes = OpenApiRouter::new()
+        .route("/tags", get(get_tags))
+        .route("/tags/{id}", get(get_tag));
+
+    OpenApiRouter::new()
+        .merge(protected_routes)
+        .merge(public_routes)
+        .with_state(app_state.clone())
+}
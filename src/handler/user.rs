@@ -1,7 +1,12 @@
 use crate::{
-    domain::{ApiResponse, CreateUserRequest, UpdateUserRequest, UserResponse},
-    middleware::jwt,
+    domain::{
+        ApiResponse, CreateUserRequest, ProblemDetails, UpdateUserRequest, UpdateUserRoleRequest,
+        UserResponse,
+    },
+    extractor::ValidatedJson,
+    middleware::{jwt, rbac},
     state::AppState,
+    utils::{AppError, Permission},
 };
 use axum::{
     extract::{Json, Path, State},
@@ -19,8 +24,10 @@ use utoipa_axum::router::OpenApiRouter;
     path = "/api/user",
     responses(
         (status = 200, description = "Create user", body = ApiResponse<UserResponse>),
-        (status = 400, description = "Invalid request body"),
-        (status = 500, description = "Internal server error")
+        (status = 400, description = "Invalid request body", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -29,12 +36,11 @@ use utoipa_axum::router::OpenApiRouter;
 )]
 pub async fn create_user(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<CreateUserRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.user_service.create_user(&body).await {
-        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+    ValidatedJson(body): ValidatedJson<CreateUserRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response = data.di_container.user_service.create_user(&body).await?;
+
+    Ok((StatusCode::CREATED, Json(json!(response))))
 }
 
 #[utoipa::path(
@@ -45,8 +51,9 @@ pub async fn create_user(
     ),
     responses(
         (status = 200, description = "Find Email user", body = ApiResponse<UserResponse>),
-        (status = 400, description = "Invalid request body"),
-        (status = 500, description = "Internal server error")
+        (status = 400, description = "Invalid request body", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -56,23 +63,15 @@ pub async fn create_user(
 pub async fn find_user_by_email(
     State(data): State<Arc<AppState>>,
     Path(email): Path<String>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data
+) -> Result<impl IntoResponse, AppError> {
+    let response = data
         .di_container
         .user_service
         .find_user_by_email(&email)
-        .await
-    {
-        Ok(Some(response)) => Ok((StatusCode::OK, Json(json!(response)))),
-        Ok(None) => Err((
-            StatusCode::NOT_FOUND,
-            Json(json!({
-                "status": "fail",
-                "message": "User not found"
-            })),
-        )),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
 }
 
 #[utoipa::path(
@@ -83,8 +82,10 @@ pub async fn find_user_by_email(
     ),
     responses(
         (status = 200, description = "Update user", body = ApiResponse<UserResponse>),
-        (status = 400, description = "Invalid request body"),
-        (status = 500, description = "Internal server error")
+        (status = 400, description = "Invalid request body", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -94,21 +95,48 @@ pub async fn find_user_by_email(
 pub async fn update_user(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Json(mut body): Json<UpdateUserRequest>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    ValidatedJson(mut body): ValidatedJson<UpdateUserRequest>,
+) -> Result<impl IntoResponse, AppError> {
     body.id = Some(id);
 
-    match data.di_container.user_service.update_user(&body).await {
-        Ok(Some(response)) => Ok((StatusCode::OK, Json(json!(response)))),
-        Ok(None) => Err((
-            StatusCode::NOT_FOUND,
-            Json(json!({
-                "status": "fail",
-                "message": "User not found"
-            })),
-        )),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+    let response = data
+        .di_container
+        .user_service
+        .update_user(&body)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/user/id/{id}/role",
+    params(
+        ("id" = i32, Path, description = "User ID")
+    ),
+    request_body = UpdateUserRoleRequest,
+    responses(
+        (status = 200, description = "Update user role", body = ApiResponse<UserResponse>),
+        (status = 403, description = "Forbidden", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails),
+        (status = 422, description = "Request validation failed", body = ProblemDetails)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+)]
+pub async fn update_user_role(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    ValidatedJson(mut body): ValidatedJson<UpdateUserRoleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    body.id = Some(id);
+
+    let response = data.di_container.user_service.update_role(&body).await?;
+
+    Ok((StatusCode::OK, Json(json!(response))))
 }
 
 #[utoipa::path(
@@ -119,8 +147,9 @@ pub async fn update_user(
     ),
     responses(
         (status = 200, description = "User", body = Value),
-        (status = 400, description = "Invalid request body"),
-        (status = 500, description = "Internal server error")
+        (status = 400, description = "Invalid request body", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails),
+        (status = 403, description = "Forbidden", body = ProblemDetails)
     ),
     security(
         ("bearer_auth" = [])
@@ -130,17 +159,16 @@ pub async fn update_user(
 pub async fn delete_user(
     State(data): State<Arc<AppState>>,
     Path(email): Path<String>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    match data.di_container.user_service.delete_user(&email).await {
-        Ok(_) => Ok((
-            StatusCode::OK,
-            Json(json!({
-                "status": "success",
-                "message": "User deleted successfully"
-            })),
-        )),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    data.di_container.user_service.delete_user(&email).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "status": "success",
+            "message": "User deleted successfully"
+        })),
+    ))
 }
 
 pub fn user_routes(app_state: Arc<AppState>) -> OpenApiRouter {
@@ -148,7 +176,12 @@ pub fn user_routes(app_state: Arc<AppState>) -> OpenApiRouter {
         .route("/api/user", post(create_user))
         .route("/api/user/email/{email}", get(find_user_by_email))
         .route("/api/user/id/{id}", put(update_user))
+        .route("/api/user/id/{id}/role", put(update_user_role))
         .route("/api/user/{email}", delete(delete_user))
+        .route_layer(middleware::from_fn_with_state(
+            Permission::ManageUsers,
+            rbac::require_permission,
+        ))
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone());
 
@@ -8,4 +8,7 @@ pub mod config;
 pub mod state;
 pub mod middleware;
 pub mod handler;
+pub mod extractor;
+pub mod storage;
+pub mod comment_filter;
 pub mod migrations;
\ No newline at end of file
@@ -2,7 +2,7 @@ use dotenv::dotenv;
 
 use example_seaorm_axum::config::{Config, ConnectionManager};
 use example_seaorm_axum::handler::AppRouter;
-use example_seaorm_axum::migrations::m20220101_000001_create_table::Migration;
+use example_seaorm_axum::migrations::Migrator;
 use example_seaorm_axum::state::AppState;
 use example_seaorm_axum::utils::tracing;
 
@@ -15,12 +15,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config = Config::init();
 
     let db_pool =
-        ConnectionManager::new_pool::<Migration>(&config.database_url, config.run_migrations)
+        ConnectionManager::new_pool::<Migrator>(&config.database_url, config.run_migrations)
             .await?;
 
     let port = config.port;
 
-    let state = AppState::new(db_pool, &config.jwt_secret);
+    let storage = config.storage.backend(&config.media_base_url)?;
+
+    let state = AppState::new(db_pool, &config, storage);
 
     println!("🚀 Server started successfully");
 
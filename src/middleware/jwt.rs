@@ -2,21 +2,20 @@ use std::sync::Arc;
 use axum::{
     body::Body,
     extract::State,
-    http::{header, Request, StatusCode},
+    http::{header, Request},
     middleware::Next,
     response::IntoResponse,
-    Json,
 };
 use axum_extra::extract::cookie::CookieJar;
 
-use crate::{domain::ErrorResponse, state::AppState};
+use crate::{state::AppState, utils::AppError};
 
 pub async fn auth(
     cookie_jar: CookieJar,
     State(data): State<Arc<AppState>>,
     mut req: Request<Body>,
     next: Next,
-) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<impl IntoResponse, AppError> {
     
     let token = cookie_jar
         .get("token")
@@ -25,45 +24,30 @@ pub async fn auth(
             req.headers()
                 .get(header::AUTHORIZATION)
                 .and_then(|auth_header| auth_header.to_str().ok())
-                .and_then(|auth_value| {
-                    if auth_value.starts_with("Bearer ") {
-                        Some(auth_value[7..].to_owned())
-                    } else {
-                        None
-                    }
-                })
+                .and_then(|auth_value| auth_value.strip_prefix("Bearer ").map(str::to_owned))
         });
 
     // Check if token exists
-    let token = match token {
-        Some(token) => token,
-        None => {
-            return Err((
-                StatusCode::UNAUTHORIZED,
-                Json(ErrorResponse {
-                    status: "fail".to_string(),
-                    message: "You are not logged in, please provide token".to_string(),
-                }),
-            ));
-        }
-    };
+    let token = token.ok_or_else(|| {
+        AppError::Unauthorized("You are not logged in, please provide token".to_string())
+    })?;
 
-    // Verify token and get user_id
-    let user_id = match data.jwt_config.verify_token(&token) {
-        Ok(id) => id,
-        Err(_) => {
-            return Err((
-                StatusCode::UNAUTHORIZED,
-                Json(ErrorResponse {
-                    status: "fail".to_string(),
-                    message: "Invalid token".to_string(),
-                }),
-            ));
-        }
-    };
+    // Verify token and get its claims
+    let claims = data.jwt_config.decode_token(&token)?;
 
-    // Insert user_id into request extensions
-    req.extensions_mut().insert(user_id);
+    // Reject tokens revoked through logout
+    if data
+        .di_container
+        .auth_service
+        .is_token_revoked(&claims.jti)
+        .await?
+    {
+        return Err(AppError::TokenRevokedError);
+    }
+
+    // Insert user_id and claims into request extensions
+    req.extensions_mut().insert(claims.user_id);
+    req.extensions_mut().insert(claims);
     
    
     Ok(next.run(req).await)
@@ -0,0 +1,57 @@
+use axum::{
+    body::Body,
+    extract::State,
+    http::Request,
+    middleware::Next,
+    response::IntoResponse,
+};
+
+use crate::{
+    config::Claims,
+    entities::sea_orm_active_enums::Role,
+    utils::{AppError, Permission},
+};
+
+// Both guards expect `jwt::auth` to have run first and inserted the caller's `Claims`.
+// Apply them per route with `route_layer(middleware::from_fn_with_state(Role::Editor, rbac::require_role))`.
+
+pub async fn require_role(
+    State(min_role): State<Role>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<impl IntoResponse, AppError> {
+    let role = caller_role(&req)?;
+
+    if !role.at_least(min_role) {
+        return Err(forbidden());
+    }
+
+    Ok(next.run(req).await)
+}
+
+pub async fn require_permission(
+    State(permission): State<Permission>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<impl IntoResponse, AppError> {
+    let role = caller_role(&req)?;
+
+    if !role.has_permission(permission) {
+        return Err(forbidden());
+    }
+
+    Ok(next.run(req).await)
+}
+
+fn caller_role(req: &Request<Body>) -> Result<Role, AppError> {
+    req.extensions()
+        .get::<Claims>()
+        .map(|claims| claims.role)
+        .ok_or_else(|| {
+            AppError::Unauthorized("You are not logged in, please provide token".to_string())
+        })
+}
+
+pub fn forbidden() -> AppError {
+    AppError::Forbidden("You do not have permission to perform this action".to_string())
+}
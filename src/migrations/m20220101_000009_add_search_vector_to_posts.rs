@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Title matches weigh more than body matches when ranking
+        db.execute_unprepared(
+            "ALTER TABLE posts ADD COLUMN IF NOT EXISTS search_vector tsvector \
+             GENERATED ALWAYS AS ( \
+                 setweight(to_tsvector('english', coalesce(title, '')), 'A') || \
+                 setweight(to_tsvector('english', coalesce(body, '')), 'B') \
+             ) STORED",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS \"idx-post-search_vector\" ON posts USING GIN (search_vector)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("DROP INDEX IF EXISTS \"idx-post-search_vector\"")
+            .await?;
+        db.execute_unprepared("ALTER TABLE posts DROP COLUMN IF EXISTS search_vector")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create comment_filter_rules table
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentFilterRules::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentFilterRules::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CommentFilterRules::Kind)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommentFilterRules::Pattern)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommentFilterRules::Action)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommentFilterRules::Hits)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(CommentFilterRules::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx-comment_filter_rules-kind-pattern")
+                            .col(CommentFilterRules::Kind)
+                            .col(CommentFilterRules::Pattern)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Duplicate detection looks back over an author's recent comments.
+        // Existing comments get the time of the migration.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Comments::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comments-user_id-created_at")
+                    .table(Comments::Table)
+                    .col(Comments::UserId)
+                    .col(Comments::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-comments-user_id-created_at")
+                    .table(Comments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .drop_column(Comments::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(CommentFilterRules::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CommentFilterRules {
+    Table,
+    Id,
+    Kind,
+    Pattern,
+    Action,
+    Hits,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Comments {
+    Table,
+    UserId,
+    CreatedAt,
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Deleting a category must no longer take its posts with it
+        replace_foreign_key(manager, ForeignKeyAction::Restrict).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        replace_foreign_key(manager, ForeignKeyAction::Cascade).await
+    }
+}
+
+async fn replace_foreign_key(
+    manager: &SchemaManager<'_>,
+    on_delete: ForeignKeyAction,
+) -> Result<(), DbErr> {
+    manager
+        .drop_foreign_key(
+            ForeignKey::drop()
+                .name("fk-post-category_id")
+                .table(Posts::Table)
+                .to_owned(),
+        )
+        .await?;
+
+    manager
+        .create_foreign_key(
+            ForeignKey::create()
+                .name("fk-post-category_id")
+                .from(Posts::Table, Posts::CategoryId)
+                .to(Categories::Table, Categories::Id)
+                .on_update(ForeignKeyAction::Cascade)
+                .on_delete(on_delete)
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    CategoryId,
+}
+
+#[derive(Iden)]
+enum Categories {
+    Table,
+    Id,
+}
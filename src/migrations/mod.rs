@@ -1,12 +1,48 @@
 pub use sea_orm_migration::prelude::*;
 
 pub mod m20220101_000001_create_table;
+pub mod m20220101_000002_create_refresh_tokens_table;
+pub mod m20220101_000003_add_role_to_users;
+pub mod m20220101_000004_add_user_id_to_comments;
+pub mod m20220101_000005_add_status_to_posts;
+pub mod m20220101_000006_add_publish_at_to_posts;
+pub mod m20220101_000007_create_post_revisions_table;
+pub mod m20220101_000008_create_tags_table;
+pub mod m20220101_000009_add_search_vector_to_posts;
+pub mod m20220101_000010_add_unique_slugs_to_posts;
+pub mod m20220101_000011_create_image_variants_table;
+pub mod m20220101_000012_create_media_table;
+pub mod m20220101_000013_add_parent_id_to_comments;
+pub mod m20220101_000014_add_moderation_to_comments;
+pub mod m20220101_000015_create_comment_filter_rules;
+pub mod m20220101_000016_add_parent_id_to_categories;
+pub mod m20220101_000017_add_slug_to_categories;
+pub mod m20220101_000018_restrict_category_delete_on_posts;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20220101_000002_create_refresh_tokens_table::Migration),
+            Box::new(m20220101_000003_add_role_to_users::Migration),
+            Box::new(m20220101_000004_add_user_id_to_comments::Migration),
+            Box::new(m20220101_000005_add_status_to_posts::Migration),
+            Box::new(m20220101_000006_add_publish_at_to_posts::Migration),
+            Box::new(m20220101_000007_create_post_revisions_table::Migration),
+            Box::new(m20220101_000008_create_tags_table::Migration),
+            Box::new(m20220101_000009_add_search_vector_to_posts::Migration),
+            Box::new(m20220101_000010_add_unique_slugs_to_posts::Migration),
+            Box::new(m20220101_000011_create_image_variants_table::Migration),
+            Box::new(m20220101_000012_create_media_table::Migration),
+            Box::new(m20220101_000013_add_parent_id_to_comments::Migration),
+            Box::new(m20220101_000014_add_moderation_to_comments::Migration),
+            Box::new(m20220101_000015_create_comment_filter_rules::Migration),
+            Box::new(m20220101_000016_add_parent_id_to_categories::Migration),
+            Box::new(m20220101_000017_add_slug_to_categories::Migration),
+            Box::new(m20220101_000018_restrict_category_delete_on_posts::Migration),
+        ]
     }
 }
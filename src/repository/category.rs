@@ -1,12 +1,112 @@
+use std::collections::{HashMap, HashSet};
+
 use async_trait::async_trait;
+use sea_orm::sea_query::{extension::postgres::PgExpr, Expr, IntoCondition};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
-    QueryFilter, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, DbBackend, DbErr, EntityTrait, FromQueryResult, JoinType, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set, SqlErr, Statement, TransactionTrait,
+    Value,
 };
 
 use crate::abstract_trait::CategoryRepositoryTrait;
 use crate::domain::{CreateCategoryRequest, UpdateCategoryRequest};
-use crate::entities::{categories, Categories};
+use crate::entities::{
+    categories, post_revisions, posts, sea_orm_active_enums::PostStatus, Categories,
+};
+use crate::utils::{contains_pattern, generate_slug};
+
+const MAX_SLUG_LENGTH: usize = 100;
+// How often a create picks a new slug after a concurrent one claimed it first
+const SLUG_ATTEMPTS: usize = 10;
+
+fn is_slug_conflict(err: &DbErr) -> bool {
+    matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(message)) if message.contains("idx-categories-slug"))
+}
+
+#[derive(FromQueryResult)]
+struct CategoryWithPostCount {
+    #[sea_orm(nested)]
+    category: categories::Model,
+    post_count: i64,
+}
+
+/// Whether `parent_id` is `id` itself or lies below it, given each category's parent.
+fn is_self_or_descendant(id: i32, parent_id: i32, parents: &HashMap<i32, Option<i32>>) -> bool {
+    let mut seen = HashSet::new();
+    let mut current = Some(parent_id);
+
+    while let Some(node) = current {
+        if node == id {
+            return true;
+        }
+        // Stop at a loop made by hand rather than walking it forever.
+        if !seen.insert(node) {
+            return false;
+        }
+        current = parents.get(&node).copied().flatten();
+    }
+
+    false
+}
+
+/// Locks `id` together with `parent_id` and its ancestors, in id order, and
+/// returns the parent of each category in that chain as read under the locks.
+/// Concurrent moves and merges wait for each other this way, so two of them
+/// cannot both pass a loop check and close a loop.
+async fn lock_chain(
+    txn: &DatabaseTransaction,
+    id: i32,
+    parent_id: i32,
+) -> Result<HashMap<i32, Option<i32>>, DbErr> {
+    txn.query_all(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        r#"WITH RECURSIVE chain AS (
+            SELECT id, parent_id FROM categories WHERE id = $1
+            UNION
+            SELECT c.id, c.parent_id FROM categories c JOIN chain ON c.id = chain.parent_id
+        )
+        SELECT id FROM categories
+        WHERE id = $2 OR id IN (SELECT id FROM chain)
+        ORDER BY id
+        FOR UPDATE"#,
+        [parent_id.into(), id.into()],
+    ))
+    .await?;
+
+    // Read the chain again now that no one else can move it.
+    Ok(Categories::find()
+        .from_raw_sql(ancestors_statement(&[parent_id]))
+        .all(txn)
+        .await?
+        .into_iter()
+        .map(|category| (category.id, category.parent_id))
+        .collect())
+}
+
+/// The given categories together with all of their ancestors.
+fn ancestors_statement(ids: &[i32]) -> Statement {
+    let placeholders = (1..=ids.len())
+        .map(|n| format!("${}", n))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let values: Vec<Value> = ids.iter().map(|id| (*id).into()).collect();
+
+    // UNION rather than UNION ALL stops at rows already seen.
+    Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        format!(
+            r#"WITH RECURSIVE chain AS (
+                SELECT * FROM categories WHERE id IN ({})
+                UNION
+                SELECT c.* FROM categories c JOIN chain ON c.id = chain.parent_id
+            )
+            SELECT * FROM chain"#,
+            placeholders
+        ),
+        values,
+    )
+}
 
 pub struct CategoryRepository {
     db_pool: DatabaseConnection,
@@ -16,6 +116,44 @@ impl CategoryRepository {
     pub fn new(db_pool: DatabaseConnection) -> Self {
         Self { db_pool }
     }
+
+    /// Picks `base`, `base-2`, `base-3`, ... skipping slugs other categories use.
+    async fn unique_slug(&self, name: &str) -> Result<String, DbErr> {
+        let mut base = generate_slug(name);
+        base.truncate(MAX_SLUG_LENGTH);
+        let base = match base.trim_end_matches('-') {
+            "" => "category".to_string(),
+            base => base.to_string(),
+        };
+
+        // Slugs only contain [a-z0-9-], so the pattern needs no escaping.
+        let taken: HashSet<String> = Categories::find()
+            .select_only()
+            .column(categories::Column::Slug)
+            .filter(
+                Condition::any()
+                    .add(categories::Column::Slug.eq(&base))
+                    .add(categories::Column::Slug.like(format!("{}-%", base))),
+            )
+            .into_tuple()
+            .all(&self.db_pool)
+            .await?
+            .into_iter()
+            .collect();
+
+        if !taken.contains(&base) {
+            return Ok(base);
+        }
+
+        let mut suffix = 2;
+        loop {
+            let candidate = format!("{}-{}", base, suffix);
+            if !taken.contains(&candidate) {
+                return Ok(candidate);
+            }
+            suffix += 1;
+        }
+    }
 }
 
 #[async_trait]
@@ -25,21 +163,42 @@ impl CategoryRepositoryTrait for CategoryRepository {
         page: i32,
         page_size: i32,
         search: Option<String>,
-    ) -> Result<(Vec<categories::Model>, i64), DbErr> {
-        let mut query = Categories::find();
+    ) -> Result<(Vec<(categories::Model, i64)>, i64), DbErr> {
+        let mut query = Categories::find()
+            .order_by_asc(categories::Column::SortOrder)
+            .order_by_asc(categories::Column::Name);
 
         if let Some(search_term) = search {
-            query = query.filter(categories::Column::Name.contains(search_term));
+            query = query
+                .filter(Expr::col(categories::Column::Name).ilike(contains_pattern(&search_term)));
         }
 
         let total_items = query.clone().count(&self.db_pool).await?;
 
+        // Grouping by the primary key lets Postgres select every category column.
         let offset = (page - 1) * page_size;
         let categories = query
+            .join(
+                JoinType::LeftJoin,
+                categories::Relation::Posts.def().on_condition(|_, posts| {
+                    Expr::col((posts, posts::Column::Status))
+                        .eq(PostStatus::Published)
+                        .into_condition()
+                }),
+            )
+            .column_as(
+                Expr::col((posts::Entity, posts::Column::Id)).count(),
+                "post_count",
+            )
+            .group_by(categories::Column::Id)
             .limit(page_size as u64)
             .offset(offset as u64)
+            .into_model::<CategoryWithPostCount>()
             .all(&self.db_pool)
-            .await?;
+            .await?
+            .into_iter()
+            .map(|row| (row.category, row.post_count))
+            .collect();
 
         Ok((categories, total_items as i64))
     }
@@ -48,41 +207,258 @@ impl CategoryRepositoryTrait for CategoryRepository {
         Categories::find_by_id(id).one(&self.db_pool).await
     }
 
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<categories::Model>, DbErr> {
+        Categories::find()
+            .filter(categories::Column::Slug.eq(slug))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn count_published_posts(&self, id: i32) -> Result<i64, DbErr> {
+        let count = posts::Entity::find()
+            .filter(posts::Column::CategoryId.eq(id))
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .count(&self.db_pool)
+            .await?;
+
+        Ok(count as i64)
+    }
+
+    async fn find_tree(&self) -> Result<Vec<categories::Model>, DbErr> {
+        Categories::find()
+            .order_by_asc(categories::Column::SortOrder)
+            .order_by_asc(categories::Column::Name)
+            .order_by_asc(categories::Column::Id)
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_ancestors(&self, ids: &[i32]) -> Result<Vec<categories::Model>, DbErr> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Categories::find()
+            .from_raw_sql(ancestors_statement(ids))
+            .all(&self.db_pool)
+            .await
+    }
+
     async fn create(&self, input: &CreateCategoryRequest) -> Result<categories::Model, DbErr> {
-        let category = categories::ActiveModel {
-            name: Set(input.name.clone()),
-            ..Default::default()
-        };
+        let mut attempt = 1;
+
+        loop {
+            let category = categories::ActiveModel {
+                name: Set(input.name.clone()),
+                slug: Set(match &input.slug {
+                    Some(slug) => slug.clone(),
+                    None => self.unique_slug(&input.name).await?,
+                }),
+                description: Set(input.description.clone()),
+                sort_order: Set(input.sort_order),
+                parent_id: Set(input.parent_id),
+                ..Default::default()
+            };
 
-        category.insert(&self.db_pool).await
+            match category.insert(&self.db_pool).await {
+                // A generated slug taken in the meantime is picked again; an explicit
+                // one is the caller's choice and surfaces as a conflict
+                Err(err)
+                    if input.slug.is_none()
+                        && attempt < SLUG_ATTEMPTS
+                        && is_slug_conflict(&err) =>
+                {
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
     }
 
-    async fn update(&self, input: &UpdateCategoryRequest) -> Result<categories::Model, DbErr> {
+    async fn update(
+        &self,
+        input: &UpdateCategoryRequest,
+    ) -> Result<Option<categories::Model>, DbErr> {
         let id = match input.id {
             Some(id) => id,
             None => return Err(DbErr::Custom("Category ID is required".to_string())),
         };
 
+        let txn = self.db_pool.begin().await?;
+
+        if let Some(Some(parent_id)) = input.parent_id {
+            let parents = lock_chain(&txn, id, parent_id).await?;
+
+            if !parents.contains_key(&parent_id) {
+                txn.rollback().await?;
+                return Err(DbErr::RecordNotFound(
+                    "Parent category not found".to_owned(),
+                ));
+            }
+
+            if is_self_or_descendant(id, parent_id, &parents) {
+                txn.rollback().await?;
+                return Ok(None);
+            }
+        }
+
         let mut category: categories::ActiveModel = Categories::find_by_id(id)
-            .one(&self.db_pool)
+            .lock_exclusive()
+            .one(&txn)
             .await?
-            .ok_or(DbErr::Custom("Category not found".to_string()))?
+            .ok_or(DbErr::RecordNotFound("Category not found".to_owned()))?
             .into();
 
         if let Some(name) = &input.name {
             category.name = Set(name.clone());
         }
 
-        category.update(&self.db_pool).await
+        if let Some(slug) = &input.slug {
+            category.slug = Set(slug.clone());
+        }
+
+        if let Some(description) = &input.description {
+            category.description = Set(description.clone());
+        }
+
+        if let Some(sort_order) = input.sort_order {
+            category.sort_order = Set(sort_order);
+        }
+
+        if let Some(parent_id) = input.parent_id {
+            category.parent_id = Set(parent_id);
+        }
+
+        let category = category.update(&txn).await?;
+        txn.commit().await?;
+
+        Ok(Some(category))
     }
 
-    async fn delete(&self, id: i32) -> Result<(), DbErr> {
-        let category: categories::ActiveModel = Categories::find_by_id(id)
-            .one(&self.db_pool)
+    async fn delete(&self, id: i32) -> Result<(u64, u64, u64), DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        // The row lock keeps posts from being filed under the category meanwhile.
+        Categories::find_by_id(id)
+            .lock_exclusive()
+            .one(&txn)
             .await?
-            .ok_or(DbErr::Custom("Category not found".to_string()))?
-            .into();
+            .ok_or(DbErr::RecordNotFound("Category not found".to_owned()))?;
+
+        let posts = posts::Entity::find()
+            .filter(posts::Column::CategoryId.eq(id))
+            .count(&txn)
+            .await?;
+
+        // Revisions have no foreign key, but restoring one needs its category.
+        let revisions = post_revisions::Entity::find()
+            .filter(post_revisions::Column::CategoryId.eq(id))
+            .count(&txn)
+            .await?;
+
+        let subcategories = Categories::find()
+            .filter(categories::Column::ParentId.eq(id))
+            .count(&txn)
+            .await?;
+
+        if posts > 0 || revisions > 0 || subcategories > 0 {
+            txn.rollback().await?;
+            return Ok((posts, revisions, subcategories));
+        }
+
+        Categories::delete_by_id(id).exec(&txn).await?;
+        txn.commit().await?;
+
+        Ok((0, 0, 0))
+    }
+
+    async fn merge(
+        &self,
+        source_id: i32,
+        target_id: i32,
+    ) -> Result<Option<(u64, u64, u64)>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        // The source's subcategories end up below the target, so it must not be one of them.
+        let parents = lock_chain(&txn, source_id, target_id).await?;
+
+        if !parents.contains_key(&target_id) {
+            txn.rollback().await?;
+            return Err(DbErr::RecordNotFound(
+                "Target category not found".to_owned(),
+            ));
+        }
+
+        if is_self_or_descendant(source_id, target_id, &parents) {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let posts_moved = posts::Entity::update_many()
+            .col_expr(posts::Column::CategoryId, Expr::value(target_id))
+            .filter(posts::Column::CategoryId.eq(source_id))
+            .exec(&txn)
+            .await?
+            .rows_affected;
+
+        let revisions_moved = post_revisions::Entity::update_many()
+            .col_expr(post_revisions::Column::CategoryId, Expr::value(target_id))
+            .filter(post_revisions::Column::CategoryId.eq(source_id))
+            .exec(&txn)
+            .await?
+            .rows_affected;
+
+        let subcategories_moved = Categories::update_many()
+            .col_expr(categories::Column::ParentId, Expr::value(target_id))
+            .filter(categories::Column::ParentId.eq(source_id))
+            .exec(&txn)
+            .await?
+            .rows_affected;
+
+        // A post filed under the source meanwhile makes this fail on the foreign key.
+        let deleted = Categories::delete_by_id(source_id).exec(&txn).await?;
+        if deleted.rows_affected == 0 {
+            txn.rollback().await?;
+            return Err(DbErr::RecordNotFound("Category not found".to_owned()));
+        }
+
+        txn.commit().await?;
+
+        Ok(Some((posts_moved, revisions_moved, subcategories_moved)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 -> 2 -> 3, with 4 as a second child of 1
+    fn parents() -> HashMap<i32, Option<i32>> {
+        HashMap::from([(1, None), (2, Some(1)), (3, Some(2)), (4, Some(1))])
+    }
+
+    #[test]
+    fn rejects_category_as_its_own_parent() {
+        assert!(is_self_or_descendant(2, 2, &parents()));
+    }
+
+    #[test]
+    fn rejects_descendant_as_parent() {
+        assert!(is_self_or_descendant(1, 3, &parents()));
+        assert!(is_self_or_descendant(2, 3, &parents()));
+    }
+
+    #[test]
+    fn allows_ancestor_sibling_or_unrelated_parent() {
+        assert!(!is_self_or_descendant(3, 1, &parents()));
+        assert!(!is_self_or_descendant(2, 4, &parents()));
+        assert!(!is_self_or_descendant(4, 99, &parents()));
+    }
+
+    #[test]
+    fn stops_at_an_existing_loop() {
+        let parents = HashMap::from([(5, Some(6)), (6, Some(5))]);
 
-        category.delete(&self.db_pool).await.map(|_| ())
+        assert!(!is_self_or_descendant(1, 5, &parents));
     }
 }
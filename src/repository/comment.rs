@@ -1,8 +1,8 @@
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait,  Set};
+use sea_orm::{prelude::DateTimeWithTimeZone, sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set, Statement};
 
-use crate::domain::{CreateCommentRequest, UpdateCommentRequest};
-use crate::entities::{comments, Comments};
+use crate::domain::{CommentSort, CreateCommentRequest, FindModerationQueueRequest, FindPostCommentsRequest, UpdateCommentRequest};
+use crate::entities::{comments, sea_orm_active_enums::CommentStatus, Comments};
 use crate::abstract_trait::CommentRepositoryTrait;
 
 pub struct CommentRepository {
@@ -13,12 +13,25 @@ impl CommentRepository {
     pub fn new(db_pool: DatabaseConnection) -> Self {
         Self { db_pool }
     }
+
+    fn post_comments(post_id: i32, req: &FindPostCommentsRequest) -> Select<Comments> {
+        let mut query = Comments::find()
+            .filter(comments::Column::IdPostComment.eq(post_id))
+            .filter(comments::Column::Status.eq(CommentStatus::Approved));
+
+        if let Some(user_id) = req.user_id {
+            query = query.filter(comments::Column::UserId.eq(user_id));
+        }
+
+        query
+    }
 }
 
 #[async_trait]
 impl CommentRepositoryTrait for CommentRepository {
-    async fn find_all(&self) -> Result<Vec<comments::Model>, DbErr> {
+    async fn find_approved(&self) -> Result<Vec<comments::Model>, DbErr> {
         Comments::find()
+            .filter(comments::Column::Status.eq(CommentStatus::Approved))
             .all(&self.db_pool)
             .await
     }
@@ -29,26 +42,163 @@ impl CommentRepositoryTrait for CommentRepository {
             .await
     }
 
-    async fn create(&self, input: &CreateCommentRequest) -> Result<comments::Model, DbErr> {
+    async fn find_depth(&self, id: i32) -> Result<Option<i32>, DbErr> {
+        let row = self
+            .db_pool
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"WITH RECURSIVE ancestors AS (
+                    SELECT id, parent_id, 0 AS depth FROM comments WHERE id = $1
+                    UNION ALL
+                    SELECT c.id, c.parent_id, a.depth + 1
+                    FROM comments c JOIN ancestors a ON c.id = a.parent_id
+                )
+                SELECT MAX(depth) AS depth FROM ancestors"#,
+                [id.into()],
+            ))
+            .await?;
+
+        match row {
+            Some(row) => row.try_get("", "depth"),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_thread(&self, post_id: i32) -> Result<Vec<comments::Model>, DbErr> {
+        Comments::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"WITH RECURSIVE thread AS (
+                    SELECT id, 0 AS depth
+                    FROM comments
+                    WHERE id_post_comment = $1 AND parent_id IS NULL AND status = 'approved'
+                    UNION ALL
+                    SELECT c.id, t.depth + 1
+                    FROM comments c JOIN thread t ON c.parent_id = t.id
+                    WHERE c.status = 'approved'
+                )
+                SELECT comments.*
+                FROM comments JOIN thread ON thread.id = comments.id
+                ORDER BY thread.depth, comments.id"#,
+                [post_id.into()],
+            ))
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_by_post(&self, post_id: i32, req: &FindPostCommentsRequest) -> Result<(Vec<comments::Model>, i64), DbErr> {
+        let query = Self::post_comments(post_id, req);
+        let total_items = query.clone().count(&self.db_pool).await?;
+
+        // Ids only grow, so they order comments by age and make a stable cursor.
+        let query = match (req.sort, req.cursor) {
+            (CommentSort::Newest, Some(cursor)) => query.filter(comments::Column::Id.lt(cursor)),
+            (CommentSort::Oldest, Some(cursor)) => query.filter(comments::Column::Id.gt(cursor)),
+            (_, None) => query,
+        };
+        let query = match req.sort {
+            CommentSort::Newest => query.order_by_desc(comments::Column::Id),
+            CommentSort::Oldest => query.order_by_asc(comments::Column::Id),
+        };
+
+        let comments = query
+            .limit(req.page_size as u64 + 1)
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((comments, total_items as i64))
+    }
+
+    async fn count_through_cursor(&self, post_id: i32, req: &FindPostCommentsRequest, cursor: i32) -> Result<i64, DbErr> {
+        let query = Self::post_comments(post_id, req);
+        let query = match req.sort {
+            CommentSort::Newest => query.filter(comments::Column::Id.gte(cursor)),
+            CommentSort::Oldest => query.filter(comments::Column::Id.lte(cursor)),
+        };
+
+        Ok(query.count(&self.db_pool).await? as i64)
+    }
+
+    async fn find_by_status(&self, req: &FindModerationQueueRequest) -> Result<(Vec<comments::Model>, i64), DbErr> {
+        let mut query = Comments::find().filter(comments::Column::Status.eq(req.status));
+
+        if let Some(post_id) = req.post_id {
+            query = query.filter(comments::Column::IdPostComment.eq(post_id));
+        }
+
+        let total_items = query.clone().count(&self.db_pool).await?;
+
+        // Oldest first, so the queue is worked through in the order it filled up.
+        let offset = (req.page - 1) * req.page_size;
+        let comments = query
+            .order_by_asc(comments::Column::Id)
+            .limit(req.page_size as u64)
+            .offset(offset as u64)
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((comments, total_items as i64))
+    }
+
+    async fn count_approved_by_user(&self, user_id: i32) -> Result<i64, DbErr> {
+        let count = Comments::find()
+            .filter(comments::Column::UserId.eq(user_id))
+            .filter(comments::Column::Status.eq(CommentStatus::Approved))
+            .count(&self.db_pool)
+            .await?;
+
+        Ok(count as i64)
+    }
+
+    async fn exists_recent_by_user(&self, user_id: i32, comment: &str, since: DateTimeWithTimeZone) -> Result<bool, DbErr> {
+        let count = Comments::find()
+            .filter(comments::Column::UserId.eq(user_id))
+            .filter(comments::Column::Comment.eq(comment))
+            .filter(comments::Column::CreatedAt.gte(since))
+            .count(&self.db_pool)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn create(&self, user_id: i32, input: &CreateCommentRequest, status: CommentStatus) -> Result<comments::Model, DbErr> {
         let comment = comments::ActiveModel {
             id_post_comment: Set(input.id_post_comment),
-            user_name_comment: Set(input.user_name_comment.clone()),
+            user_id: Set(Some(user_id)),
             comment: Set(input.comment.clone()),
+            parent_id: Set(input.parent_id),
+            status: Set(status),
             ..Default::default()
         };
 
         comment.insert(&self.db_pool).await
     }
 
-    async fn update(&self, input: &UpdateCommentRequest) -> Result<comments::Model, DbErr> {
-        let mut comment: comments::ActiveModel = Comments::find_by_id(input.id_post_comment)
+    async fn update_status(&self, ids: &[i32], status: CommentStatus) -> Result<Vec<comments::Model>, DbErr> {
+        let mut comments = Comments::update_many()
+            .col_expr(comments::Column::Status, Expr::value(status))
+            .filter(comments::Column::Id.is_in(ids.iter().copied()))
+            .exec_with_returning(&self.db_pool)
+            .await?;
+        comments.sort_by_key(|comment| comment.id);
+
+        Ok(comments)
+    }
+
+    async fn update(&self, input: &UpdateCommentRequest, status: CommentStatus) -> Result<comments::Model, DbErr> {
+        let id = match input.id {
+            Some(id) => id,
+            None => return Err(DbErr::Custom("Comment ID is required".to_string())),
+        };
+
+        let mut comment: comments::ActiveModel = Comments::find_by_id(id)
             .one(&self.db_pool)
             .await?
             .ok_or(DbErr::Custom("Comment not found".to_string()))?
             .into();
 
-        comment.user_name_comment = Set(input.user_name_comment.clone());
         comment.comment = Set(input.comment.clone());
+        comment.status = Set(status);
 
         comment.update(&self.db_pool).await
     }
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+
+use crate::abstract_trait::CommentFilterRepositoryTrait;
+use crate::domain::{CreateCommentFilterRuleRequest, UpdateCommentFilterRuleRequest};
+use crate::entities::{comment_filter_rules, CommentFilterRules};
+
+pub struct CommentFilterRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl CommentFilterRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl CommentFilterRepositoryTrait for CommentFilterRepository {
+    async fn find_all(&self) -> Result<Vec<comment_filter_rules::Model>, DbErr> {
+        CommentFilterRules::find()
+            .order_by_asc(comment_filter_rules::Column::Id)
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<comment_filter_rules::Model>, DbErr> {
+        CommentFilterRules::find_by_id(id).one(&self.db_pool).await
+    }
+
+    async fn create(
+        &self,
+        input: &CreateCommentFilterRuleRequest,
+    ) -> Result<comment_filter_rules::Model, DbErr> {
+        let rule = comment_filter_rules::ActiveModel {
+            kind: Set(input.kind),
+            pattern: Set(input.pattern.clone()),
+            action: Set(input.action),
+            ..Default::default()
+        };
+
+        rule.insert(&self.db_pool).await
+    }
+
+    async fn update(
+        &self,
+        id: i32,
+        input: &UpdateCommentFilterRuleRequest,
+    ) -> Result<Option<comment_filter_rules::Model>, DbErr> {
+        let Some(rule) = CommentFilterRules::find_by_id(id)
+            .one(&self.db_pool)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let mut rule: comment_filter_rules::ActiveModel = rule.into();
+        rule.kind = Set(input.kind);
+        rule.pattern = Set(input.pattern.clone());
+        rule.action = Set(input.action);
+
+        rule.update(&self.db_pool).await.map(Some)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DbErr> {
+        let result = CommentFilterRules::delete_by_id(id)
+            .exec(&self.db_pool)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound(
+                "Comment filter rule not found".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn record_hits(&self, ids: &[i32]) -> Result<(), DbErr> {
+        CommentFilterRules::update_many()
+            .col_expr(
+                comment_filter_rules::Column::Hits,
+                Expr::col(comment_filter_rules::Column::Hits).add(1),
+            )
+            .filter(comment_filter_rules::Column::Id.is_in(ids.iter().copied()))
+            .exec(&self.db_pool)
+            .await
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+
+use crate::abstract_trait::ImageVariantRepositoryTrait;
+use crate::entities::{image_variants, ImageVariants};
+
+pub struct ImageVariantRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl ImageVariantRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ImageVariantRepositoryTrait for ImageVariantRepository {
+    async fn create(
+        &self,
+        image_key: &str,
+        key: &str,
+        width: i32,
+        height: i32,
+        content_type: &str,
+    ) -> Result<image_variants::Model, DbErr> {
+        let variant = image_variants::ActiveModel {
+            image_key: Set(image_key.to_string()),
+            key: Set(key.to_string()),
+            width: Set(width),
+            height: Set(height),
+            content_type: Set(content_type.to_string()),
+            created_at: Set(Utc::now().fixed_offset()),
+            ..Default::default()
+        };
+
+        variant.insert(&self.db_pool).await
+    }
+
+    async fn find_by_image_keys(
+        &self,
+        image_keys: &[String],
+    ) -> Result<Vec<image_variants::Model>, DbErr> {
+        if image_keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        ImageVariants::find()
+            .filter(image_variants::Column::ImageKey.is_in(image_keys.iter().cloned()))
+            .order_by_asc(image_variants::Column::Width)
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn delete_by_image_key(&self, image_key: &str) -> Result<(), DbErr> {
+        ImageVariants::delete_many()
+            .filter(image_variants::Column::ImageKey.eq(image_key))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::sea_query::{extension::postgres::PgExpr, Expr, Query};
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+use std::collections::BTreeSet;
+
+use crate::abstract_trait::MediaRepositoryTrait;
+use crate::domain::CreateMediaRequest;
+use crate::entities::{media, post_revisions, posts, Media, PostRevisions, Posts};
+use crate::utils::contains_pattern;
+
+pub struct MediaRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl MediaRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MediaRepositoryTrait for MediaRepository {
+    async fn find_all(
+        &self,
+        page: i32,
+        page_size: i32,
+        search: Option<String>,
+        mime: Option<String>,
+    ) -> Result<(Vec<media::Model>, i64), DbErr> {
+        let mut query = Media::find()
+            .order_by_desc(media::Column::CreatedAt)
+            .order_by_desc(media::Column::Id);
+
+        if let Some(search_term) = search {
+            query = query
+                .filter(Expr::col(media::Column::FileName).ilike(contains_pattern(&search_term)));
+        }
+
+        if let Some(mime) = mime {
+            query = query.filter(media::Column::Mime.eq(mime));
+        }
+
+        let total_items = query.clone().count(&self.db_pool).await?;
+
+        let offset = (page - 1) * page_size;
+        let media = query
+            .limit(page_size as u64)
+            .offset(offset as u64)
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((media, total_items as i64))
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<media::Model>, DbErr> {
+        Media::find_by_id(id).one(&self.db_pool).await
+    }
+
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<media::Model>, DbErr> {
+        Media::find()
+            .filter(media::Column::Hash.eq(hash))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn create(&self, input: &CreateMediaRequest) -> Result<media::Model, DbErr> {
+        let media = media::ActiveModel {
+            hash: Set(input.hash.clone()),
+            path: Set(input.path.clone()),
+            file_name: Set(input.file_name.clone()),
+            mime: Set(input.mime.clone()),
+            size: Set(input.size),
+            width: Set(input.width),
+            height: Set(input.height),
+            user_id: Set(Some(input.user_id)),
+            created_at: Set(Utc::now().fixed_offset()),
+            ..Default::default()
+        };
+
+        media.insert(&self.db_pool).await
+    }
+
+    async fn count_references(&self, id: i32) -> Result<u64, DbErr> {
+        let posts = Posts::find()
+            .filter(posts::Column::MediaId.eq(id))
+            .count(&self.db_pool)
+            .await?;
+
+        let revisions = PostRevisions::find()
+            .filter(post_revisions::Column::MediaId.eq(id))
+            .count(&self.db_pool)
+            .await?;
+
+        Ok(posts + revisions)
+    }
+
+    async fn find_unreferenced(
+        &self,
+        created_before: DateTimeWithTimeZone,
+    ) -> Result<Vec<media::Model>, DbErr> {
+        Media::find()
+            .filter(media::Column::CreatedAt.lt(created_before))
+            .filter(
+                media::Column::Id.not_in_subquery(
+                    Query::select()
+                        .column(posts::Column::MediaId)
+                        .from(Posts)
+                        .and_where(posts::Column::MediaId.is_not_null())
+                        .to_owned(),
+                ),
+            )
+            .filter(
+                media::Column::Id.not_in_subquery(
+                    Query::select()
+                        .column(post_revisions::Column::MediaId)
+                        .from(PostRevisions)
+                        .and_where(post_revisions::Column::MediaId.is_not_null())
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(media::Column::Id)
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_paths(&self) -> Result<Vec<String>, DbErr> {
+        Media::find()
+            .select_only()
+            .column(media::Column::Path)
+            .into_tuple()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_image_references(&self) -> Result<Vec<String>, DbErr> {
+        let posts: Vec<String> = Posts::find()
+            .select_only()
+            .column(posts::Column::Img)
+            .distinct()
+            .into_tuple()
+            .all(&self.db_pool)
+            .await?;
+
+        let revisions: Vec<String> = PostRevisions::find()
+            .select_only()
+            .column(post_revisions::Column::Img)
+            .distinct()
+            .into_tuple()
+            .all(&self.db_pool)
+            .await?;
+
+        Ok(posts
+            .into_iter()
+            .chain(revisions)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect())
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DbErr> {
+        Media::delete_by_id(id).exec(&self.db_pool).await?;
+
+        Ok(())
+    }
+}
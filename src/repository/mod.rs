@@ -1,9 +1,19 @@
 mod category;
 mod posts;
 mod comment;
+mod comment_filter;
+mod image_variant;
+mod media;
+mod tag;
+mod token;
 mod user;
 
 pub use self::category::CategoryRepository;
 pub use self::posts::PostRepository;
 pub use self::comment::CommentRepository;
-pub use self::user::UserRepository;
\ No newline at end of file
+pub use self::comment_filter::CommentFilterRepository;
+pub use self::image_variant::ImageVariantRepository;
+pub use self::media::MediaRepository;
+pub use self::tag::TagRepository;
+pub use self::token::TokenRepository;
+pub use self::user::UserRepository;
@@ -1,13 +1,49 @@
 use crate::abstract_trait::PostsRepositoryTrait;
-use crate::domain::{CreatePostRequest, PostRelationResponse, UpdatePostRequest};
-use crate::entities::{comments, posts};
+use crate::domain::{
+    CreatePostRequest, PostFilter, PostRelationResponse, PostVisibility, TagMatch,
+    UpdatePostRequest,
+};
+use crate::entities::{
+    comments, post_revisions, post_slug_history, post_tags, posts,
+    sea_orm_active_enums::{CommentStatus, PostStatus},
+    tags,
+};
+use crate::utils::{contains_pattern, generate_slug};
 use async_trait::async_trait;
+use sea_orm::sea_query::{
+    extension::postgres::PgExpr, Expr, Func, LockBehavior, LockType, OnConflict, Query,
+};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ModelTrait,
-    PaginatorTrait, QueryFilter, QuerySelect, Set,
+    prelude::DateTimeWithTimeZone, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait,
+    DatabaseConnection, DbErr, EntityTrait, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select, SelectTwoMany, Set, SqlErr, TransactionTrait,
 };
+use std::collections::{HashMap, HashSet};
 use tracing::{error, info};
 
+// Leaves room for a numeric suffix inside the 255 character column.
+const MAX_SLUG_LENGTH: usize = 200;
+
+// How often a write picks a new slug after a concurrent one claimed it first;
+// every round has a winner, so this many writers racing for a title all succeed.
+const SLUG_ATTEMPTS: usize = 10;
+
+fn is_slug_conflict(err: &DbErr) -> bool {
+    matches!(
+        err.sql_err(),
+        Some(SqlErr::UniqueConstraintViolation(message)) if message.contains("idx-post-slug")
+    )
+}
+
+/// The post with the comments anyone may read; pending, spam and rejected
+/// ones stay with their authors and moderators.
+fn post_with_approved_comments(post_id: i32) -> SelectTwoMany<posts::Entity, comments::Entity> {
+    posts::Entity::find()
+        .filter(posts::Column::Id.eq(post_id))
+        .find_with_related(comments::Entity)
+        .filter(comments::Column::Status.eq(CommentStatus::Approved))
+}
+
 pub struct PostRepository {
     db_pool: DatabaseConnection,
 }
@@ -16,6 +52,266 @@ impl PostRepository {
     pub fn new(db_pool: DatabaseConnection) -> Self {
         Self { db_pool }
     }
+
+    /// Applies visibility, status and tag filters shared by listing and search.
+    fn filtered_query(filter: &PostFilter) -> Select<posts::Entity> {
+        let mut query = posts::Entity::find();
+
+        query = match filter.visibility {
+            PostVisibility::Public => {
+                query.filter(posts::Column::Status.eq(PostStatus::Published))
+            }
+            PostVisibility::Author(user_id) => query.filter(
+                Condition::any()
+                    .add(posts::Column::Status.eq(PostStatus::Published))
+                    .add(posts::Column::UserId.eq(user_id)),
+            ),
+            PostVisibility::Staff => query,
+        };
+
+        if let Some(status) = filter.status {
+            query = query.filter(posts::Column::Status.eq(status));
+        }
+
+        if !filter.tags.is_empty() {
+            let mut tagged = Query::select();
+            tagged
+                .column((post_tags::Entity, post_tags::Column::PostId))
+                .from(post_tags::Entity)
+                .inner_join(
+                    tags::Entity,
+                    Expr::col((tags::Entity, tags::Column::Id))
+                        .equals((post_tags::Entity, post_tags::Column::TagId)),
+                )
+                .and_where(Expr::col((tags::Entity, tags::Column::Name)).is_in(filter.tags.clone()));
+
+            if filter.tag_match == TagMatch::All {
+                tagged
+                    .group_by_col((post_tags::Entity, post_tags::Column::PostId))
+                    .and_having(
+                        Expr::expr(Func::count_distinct(Expr::col((
+                            post_tags::Entity,
+                            post_tags::Column::TagId,
+                        ))))
+                        .eq(filter.tags.len() as i64),
+                    );
+            }
+
+            query = query.filter(posts::Column::Id.in_subquery(tagged.to_owned()));
+        }
+
+        if let Some(category_id) = filter.category_id {
+            query = query.filter(Expr::cust_with_values(
+                r#"posts.category_id IN (
+                    WITH RECURSIVE subtree AS (
+                        SELECT id FROM categories WHERE id = $1
+                        UNION
+                        SELECT c.id FROM categories c JOIN subtree s ON c.parent_id = s.id
+                    )
+                    SELECT id FROM subtree
+                )"#,
+                [category_id],
+            ));
+        }
+
+        query
+    }
+
+    /// Locks the post row so revision numbers are handed out one writer at a time.
+    async fn find_post_for_update<C: ConnectionTrait>(
+        conn: &C,
+        post_id: i32,
+    ) -> Result<posts::Model, DbErr> {
+        posts::Entity::find_by_id(post_id)
+            .lock_exclusive()
+            .one(conn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))
+    }
+
+    /// Picks `base`, `base-2`, `base-3`, ... skipping slugs other posts use now or used before.
+    async fn unique_slug<C: ConnectionTrait>(
+        conn: &C,
+        title: &str,
+        post_id: Option<i32>,
+    ) -> Result<String, DbErr> {
+        let mut base = generate_slug(title);
+        base.truncate(MAX_SLUG_LENGTH);
+        let base = match base.trim_end_matches('-') {
+            "" => "post".to_string(),
+            base => base.to_string(),
+        };
+
+        // Slugs only contain [a-z0-9-], so the pattern needs no escaping.
+        let pattern = format!("{}-%", base);
+
+        let mut current = posts::Entity::find()
+            .select_only()
+            .column(posts::Column::Slug)
+            .filter(
+                Condition::any()
+                    .add(posts::Column::Slug.eq(&base))
+                    .add(posts::Column::Slug.like(&pattern)),
+            );
+        let mut previous = post_slug_history::Entity::find()
+            .select_only()
+            .column(post_slug_history::Column::Slug)
+            .filter(
+                Condition::any()
+                    .add(post_slug_history::Column::Slug.eq(&base))
+                    .add(post_slug_history::Column::Slug.like(&pattern)),
+            );
+
+        if let Some(post_id) = post_id {
+            current = current.filter(posts::Column::Id.ne(post_id));
+            previous = previous.filter(post_slug_history::Column::PostId.ne(post_id));
+        }
+
+        let mut taken: HashSet<String> = current.into_tuple().all(conn).await?.into_iter().collect();
+        taken.extend(previous.into_tuple::<String>().all(conn).await?);
+
+        if !taken.contains(&base) {
+            return Ok(base);
+        }
+
+        let mut suffix = 2;
+        loop {
+            let candidate = format!("{}-{}", base, suffix);
+            if !taken.contains(&candidate) {
+                return Ok(candidate);
+            }
+            suffix += 1;
+        }
+    }
+
+    /// Returns the new slug when `title` changes it, keeping the old slug as a redirect.
+    async fn change_slug<C: ConnectionTrait>(
+        conn: &C,
+        post: &posts::Model,
+        title: &str,
+    ) -> Result<Option<String>, DbErr> {
+        if post.title == title {
+            return Ok(None);
+        }
+
+        let slug = Self::unique_slug(conn, title, Some(post.id)).await?;
+        if slug == post.slug {
+            return Ok(None);
+        }
+
+        // A post may take back one of its own earlier slugs.
+        post_slug_history::Entity::delete_many()
+            .filter(post_slug_history::Column::PostId.eq(post.id))
+            .filter(post_slug_history::Column::Slug.eq(&slug))
+            .exec(conn)
+            .await?;
+
+        let previous = post_slug_history::ActiveModel {
+            post_id: Set(post.id),
+            slug: Set(post.slug.clone()),
+            ..Default::default()
+        };
+
+        post_slug_history::Entity::insert(previous)
+            .on_conflict(
+                OnConflict::column(post_slug_history::Column::Slug)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(conn)
+            .await?;
+
+        Ok(Some(slug))
+    }
+
+    /// Saves `changes` to `post`, moving it to a new slug when `title` changes it.
+    /// Each attempt runs in a savepoint, so losing a slug to a concurrent writer
+    /// only repeats the slug pick.
+    async fn update_with_slug<C: ConnectionTrait + TransactionTrait>(
+        conn: &C,
+        post: &posts::Model,
+        title: &str,
+        changes: posts::ActiveModel,
+    ) -> Result<posts::Model, DbErr> {
+        let mut attempt = 1;
+        loop {
+            let savepoint = conn.begin().await?;
+
+            let mut update = changes.clone();
+            if let Some(slug) = Self::change_slug(&savepoint, post, title).await? {
+                update.slug = Set(slug);
+            }
+
+            match update.update(&savepoint).await {
+                Ok(post) => {
+                    savepoint.commit().await?;
+                    return Ok(post);
+                }
+                Err(e) if attempt < SLUG_ATTEMPTS && is_slug_conflict(&e) => {
+                    savepoint.rollback().await?;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn replace_tags<C: ConnectionTrait>(
+        conn: &C,
+        post_id: i32,
+        tag_ids: &[i32],
+    ) -> Result<(), DbErr> {
+        post_tags::Entity::delete_many()
+            .filter(post_tags::Column::PostId.eq(post_id))
+            .exec(conn)
+            .await?;
+
+        if tag_ids.is_empty() {
+            return Ok(());
+        }
+
+        let links = tag_ids.iter().map(|&tag_id| post_tags::ActiveModel {
+            post_id: Set(post_id),
+            tag_id: Set(tag_id),
+        });
+
+        post_tags::Entity::insert_many(links)
+            .on_conflict_do_nothing()
+            .exec(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn record_revision<C: ConnectionTrait>(
+        conn: &C,
+        post: &posts::Model,
+        edited_by: i32,
+    ) -> Result<post_revisions::Model, DbErr> {
+        let latest: Option<i32> = post_revisions::Entity::find()
+            .select_only()
+            .column_as(post_revisions::Column::Revision.max(), "revision")
+            .filter(post_revisions::Column::PostId.eq(post.id))
+            .into_tuple()
+            .one(conn)
+            .await?
+            .flatten();
+
+        post_revisions::ActiveModel {
+            post_id: Set(post.id),
+            revision: Set(latest.unwrap_or(0) + 1),
+            title: Set(post.title.clone()),
+            body: Set(post.body.clone()),
+            img: Set(post.img.clone()),
+            media_id: Set(post.media_id),
+            category_id: Set(post.category_id),
+            edited_by: Set(Some(edited_by)),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await
+    }
 }
 
 #[async_trait]
@@ -24,14 +320,12 @@ impl PostsRepositoryTrait for PostRepository {
         &self,
         page: i32,
         page_size: i32,
-        search: Option<String>,
+        filter: &PostFilter,
     ) -> Result<(Vec<posts::Model>, i64), DbErr> {
-        use sea_orm::{ColumnTrait, QueryFilter};
-
-        let mut query = posts::Entity::find();
+        let mut query = Self::filtered_query(filter);
 
-        if let Some(search) = search {
-            query = query.filter(posts::Column::Title.contains(&search));
+        if let Some(search) = &filter.search {
+            query = query.filter(Expr::col(posts::Column::Title).ilike(contains_pattern(search)));
         }
 
         let total_count = query.clone().count(&self.db_pool).await?;
@@ -45,16 +339,87 @@ impl PostsRepositoryTrait for PostRepository {
         Ok((posts, total_count as i64))
     }
 
+    async fn search_posts(
+        &self,
+        page: i32,
+        page_size: i32,
+        filter: &PostFilter,
+        tsquery: &str,
+    ) -> Result<(Vec<(posts::Model, f32, String)>, i64), DbErr> {
+        let query = Self::filtered_query(filter).filter(Expr::cust_with_values(
+            "search_vector @@ to_tsquery('english', $1)",
+            [tsquery],
+        ));
+
+        let total_count = query.clone().count(&self.db_pool).await?;
+
+        let rank = Expr::cust_with_values(
+            "ts_rank(search_vector, to_tsquery('english', $1))",
+            [tsquery],
+        );
+        let headline = Expr::cust_with_values(
+            "ts_headline('english', body, to_tsquery('english', $1), \
+             'StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=30, MinWords=10')",
+            [tsquery],
+        );
+
+        let hits: Vec<(i32, f32, String)> = query
+            .select_only()
+            .column(posts::Column::Id)
+            .column_as(rank.clone(), "rank")
+            .column_as(headline, "headline")
+            .order_by_desc(rank)
+            .order_by_desc(posts::Column::Id)
+            .limit(page_size as u64)
+            .offset(((page - 1) * page_size) as u64)
+            .into_tuple()
+            .all(&self.db_pool)
+            .await?;
+
+        let mut models: HashMap<i32, posts::Model> = posts::Entity::find()
+            .filter(posts::Column::Id.is_in(hits.iter().map(|(id, _, _)| *id)))
+            .all(&self.db_pool)
+            .await?
+            .into_iter()
+            .map(|post| (post.id, post))
+            .collect();
+
+        // Keep the ranking order of the search query.
+        let posts = hits
+            .into_iter()
+            .filter_map(|(id, rank, headline)| {
+                models.remove(&id).map(|post| (post, rank, headline))
+            })
+            .collect();
+
+        Ok((posts, total_count as i64))
+    }
+
     async fn get_post(&self, post_id: i32) -> Result<Option<posts::Model>, DbErr> {
         posts::Entity::find_by_id(post_id).one(&self.db_pool).await
     }
 
+    async fn get_post_by_slug(&self, slug: &str) -> Result<Option<posts::Model>, DbErr> {
+        posts::Entity::find()
+            .filter(posts::Column::Slug.eq(slug))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn get_post_by_previous_slug(&self, slug: &str) -> Result<Option<posts::Model>, DbErr> {
+        let previous = post_slug_history::Entity::find()
+            .filter(post_slug_history::Column::Slug.eq(slug))
+            .find_also_related(posts::Entity)
+            .one(&self.db_pool)
+            .await?;
+
+        Ok(previous.and_then(|(_, post)| post))
+    }
+
     async fn get_post_relation(&self, post_id: i32) -> Result<Vec<PostRelationResponse>, DbErr> {
         info!("Fetching post relation for post ID: {}", post_id);
 
-        match posts::Entity::find()
-            .filter(posts::Column::Id.eq(post_id))
-            .find_with_related(comments::Entity)
+        match post_with_approved_comments(post_id)
             .all(&self.db_pool)
             .await
         {
@@ -91,47 +456,237 @@ impl PostsRepositoryTrait for PostRepository {
         }
     }
 
-    async fn create_post(&self, input: &CreatePostRequest) -> Result<posts::Model, DbErr> {
-        let new_post = posts::ActiveModel {
-            title: Set(input.title.to_string()),
-            body: Set(input.body.to_string()),
-            slug: Set(input.title.to_string()),
-            img: Set(input.file.to_string()),
-            category_id: Set(input.category_id),
-            user_id: Set(input.user_id),
-            user_name: Set(input.user_name.to_string()),
-            ..Default::default()
-        };
+    async fn create_post(
+        &self,
+        input: &CreatePostRequest,
+        user_id: i32,
+        user_name: &str,
+    ) -> Result<posts::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
 
-        match new_post.insert(&self.db_pool).await {
-            Ok(post) => Ok(post),
-            Err(e) => {
-                error!("Failed to create post: {:?}", e);
-                Err(e)
+        let mut attempt = 1;
+        let post = loop {
+            let new_post = posts::ActiveModel {
+                title: Set(input.title.to_string()),
+                body: Set(input.body.to_string()),
+                slug: Set(Self::unique_slug(&txn, &input.title, None).await?),
+                img: Set(input.file.to_string()),
+                media_id: Set(input.media_id),
+                category_id: Set(input.category_id),
+                user_id: Set(user_id),
+                user_name: Set(user_name.to_string()),
+                ..Default::default()
+            };
+
+            // A concurrent create may claim the same slug between the pick and the insert.
+            let savepoint = txn.begin().await?;
+            match new_post.insert(&savepoint).await {
+                Ok(post) => {
+                    savepoint.commit().await?;
+                    break post;
+                }
+                Err(e) if attempt < SLUG_ATTEMPTS && is_slug_conflict(&e) => {
+                    savepoint.rollback().await?;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    error!("Failed to create post: {:?}", e);
+                    return Err(e);
+                }
             }
-        }
+        };
+
+        Self::replace_tags(&txn, post.id, &input.tag_ids).await?;
+        Self::record_revision(&txn, &post, user_id).await?;
+        txn.commit().await?;
+
+        Ok(post)
     }
 
-    async fn update_post(&self, input: &UpdatePostRequest) -> Result<posts::Model, DbErr> {
+    async fn update_post(
+        &self,
+        input: &UpdatePostRequest,
+        edited_by: i32,
+    ) -> Result<posts::Model, DbErr> {
         let id = match input.post_id {
             Some(id) => id,
             None => return Err(DbErr::Custom("Post ID is required".to_string())),
         };
 
-        let post = posts::Entity::find_by_id(id)
-            .one(&self.db_pool)
-            .await?
-            .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
+        let txn = self.db_pool.begin().await?;
 
-        let mut post: posts::ActiveModel = post.into();
+        let current = Self::find_post_for_update(&txn, id).await?;
+
+        let mut post: posts::ActiveModel = current.clone().into();
         post.title = Set(input.title.to_string());
         post.body = Set(input.body.to_string());
-        post.img = Set(input.file.to_string());
+        if let Some(file) = &input.file {
+            post.img = Set(file.clone());
+            post.media_id = Set(input.media_id);
+        }
         post.category_id = Set(input.category_id);
-        post.user_id = Set(input.user_id);
-        post.user_name = Set(input.user_name.to_string());
 
-        post.update(&self.db_pool).await
+        let post = Self::update_with_slug(&txn, &current, &input.title, post).await?;
+
+        if let Some(tag_ids) = &input.tag_ids {
+            Self::replace_tags(&txn, post.id, tag_ids).await?;
+        }
+
+        Self::record_revision(&txn, &post, edited_by).await?;
+        txn.commit().await?;
+
+        Ok(post)
+    }
+
+    async fn get_revisions(&self, post_id: i32) -> Result<Vec<post_revisions::Model>, DbErr> {
+        post_revisions::Entity::find()
+            .filter(post_revisions::Column::PostId.eq(post_id))
+            .order_by_desc(post_revisions::Column::Revision)
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn get_revision(
+        &self,
+        post_id: i32,
+        revision: i32,
+    ) -> Result<Option<post_revisions::Model>, DbErr> {
+        post_revisions::Entity::find()
+            .filter(post_revisions::Column::PostId.eq(post_id))
+            .filter(post_revisions::Column::Revision.eq(revision))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn restore_revision(
+        &self,
+        post_id: i32,
+        revision: i32,
+        edited_by: i32,
+    ) -> Result<Option<posts::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let post = Self::find_post_for_update(&txn, post_id).await?;
+
+        let Some(source) = post_revisions::Entity::find()
+            .filter(post_revisions::Column::PostId.eq(post_id))
+            .filter(post_revisions::Column::Revision.eq(revision))
+            .one(&txn)
+            .await?
+        else {
+            txn.rollback().await?;
+            return Ok(None);
+        };
+
+        let mut changes: posts::ActiveModel = post.clone().into();
+        changes.title = Set(source.title.clone());
+        changes.body = Set(source.body);
+        changes.img = Set(source.img);
+        changes.media_id = Set(source.media_id);
+        changes.category_id = Set(source.category_id);
+
+        let post = Self::update_with_slug(&txn, &post, &source.title, changes).await?;
+
+        // Restoring appends a new revision instead of rewinding history.
+        Self::record_revision(&txn, &post, edited_by).await?;
+        txn.commit().await?;
+
+        Ok(Some(post))
+    }
+
+    async fn update_status(
+        &self,
+        post_id: i32,
+        from: &[PostStatus],
+        status: PostStatus,
+        published_at: Option<DateTimeWithTimeZone>,
+    ) -> Result<Option<posts::Model>, DbErr> {
+        let mut update = posts::Entity::update_many()
+            .col_expr(posts::Column::Status, Expr::value(status))
+            .filter(posts::Column::Id.eq(post_id))
+            .filter(posts::Column::Status.is_in(from.iter().copied()));
+
+        if let Some(published_at) = published_at {
+            update = update.col_expr(posts::Column::PublishedAt, Expr::value(published_at));
+        }
+
+        // The status guard makes concurrent transitions on the same post fail instead of overwrite.
+        let updated = update.exec_with_returning(&self.db_pool).await?;
+
+        Ok(updated.into_iter().next())
+    }
+
+    async fn schedule_post(
+        &self,
+        post_id: i32,
+        from: &[PostStatus],
+        publish_at: DateTimeWithTimeZone,
+    ) -> Result<Option<posts::Model>, DbErr> {
+        let updated = posts::Entity::update_many()
+            .col_expr(posts::Column::Status, Expr::value(PostStatus::Scheduled))
+            .col_expr(posts::Column::PublishAt, Expr::value(publish_at))
+            .filter(posts::Column::Id.eq(post_id))
+            .filter(posts::Column::Status.is_in(from.iter().copied()))
+            .exec_with_returning(&self.db_pool)
+            .await?;
+
+        Ok(updated.into_iter().next())
+    }
+
+    async fn publish_due_posts(
+        &self,
+        now: DateTimeWithTimeZone,
+        limit: u64,
+    ) -> Result<Vec<posts::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        // SKIP LOCKED lets every replica run the scheduler without publishing a post twice.
+        let due: Vec<i32> = posts::Entity::find()
+            .select_only()
+            .column(posts::Column::Id)
+            .filter(posts::Column::Status.eq(PostStatus::Scheduled))
+            .filter(posts::Column::PublishAt.lte(now))
+            .order_by_asc(posts::Column::PublishAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .into_tuple()
+            .all(&txn)
+            .await?;
+
+        if due.is_empty() {
+            txn.commit().await?;
+            return Ok(Vec::new());
+        }
+
+        let published = posts::Entity::update_many()
+            .col_expr(posts::Column::Status, Expr::value(PostStatus::Published))
+            .col_expr(posts::Column::PublishedAt, Expr::col(posts::Column::PublishAt).into())
+            .filter(posts::Column::Id.is_in(due))
+            .exec_with_returning(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        info!("Published {} scheduled post(s)", published.len());
+
+        Ok(published)
+    }
+
+    async fn set_comments_require_approval(
+        &self,
+        post_id: i32,
+        require_approval: bool,
+    ) -> Result<Option<posts::Model>, DbErr> {
+        let updated = posts::Entity::update_many()
+            .col_expr(
+                posts::Column::CommentsRequireApproval,
+                Expr::value(require_approval),
+            )
+            .filter(posts::Column::Id.eq(post_id))
+            .exec_with_returning(&self.db_pool)
+            .await?;
+
+        Ok(updated.into_iter().next())
     }
 
     async fn delete_post(&self, post_id: i32) -> Result<(), DbErr> {
@@ -144,3 +699,22 @@ impl PostsRepositoryTrait for PostRepository {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn post_relation_skips_unapproved_comments() {
+        let sql = post_with_approved_comments(1)
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(r#""comments"."status" = 'approved'"#),
+            "{}",
+            sql
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use sea_orm::sea_query::{extension::postgres::PgExpr, Expr};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, JoinType,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set,
+};
+
+use crate::abstract_trait::TagRepositoryTrait;
+use crate::entities::{post_tags, posts, sea_orm_active_enums::PostStatus, tags, Tags};
+use crate::utils::contains_pattern;
+
+pub struct TagRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl TagRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TagRepositoryTrait for TagRepository {
+    async fn find_all(
+        &self,
+        page: i32,
+        page_size: i32,
+        search: Option<String>,
+    ) -> Result<(Vec<tags::Model>, i64), DbErr> {
+        let mut query = Tags::find().order_by_asc(tags::Column::Name);
+
+        if let Some(search_term) = search {
+            query =
+                query.filter(Expr::col(tags::Column::Name).ilike(contains_pattern(&search_term)));
+        }
+
+        let total_items = query.clone().count(&self.db_pool).await?;
+
+        let offset = (page - 1) * page_size;
+        let tags = query
+            .limit(page_size as u64)
+            .offset(offset as u64)
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((tags, total_items as i64))
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<tags::Model>, DbErr> {
+        Tags::find_by_id(id).one(&self.db_pool).await
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<tags::Model>, DbErr> {
+        Tags::find()
+            .filter(tags::Column::Name.eq(name))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<tags::Model>, DbErr> {
+        Tags::find()
+            .filter(tags::Column::Id.is_in(ids.iter().copied()))
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_by_posts(&self, post_ids: &[i32]) -> Result<Vec<(i32, tags::Model)>, DbErr> {
+        let rows = post_tags::Entity::find()
+            .filter(post_tags::Column::PostId.is_in(post_ids.iter().copied()))
+            .find_also_related(Tags)
+            .order_by_asc(tags::Column::Name)
+            .all(&self.db_pool)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(link, tag)| tag.map(|tag| (link.post_id, tag)))
+            .collect())
+    }
+
+    async fn count_published_posts(&self, tag_ids: &[i32]) -> Result<Vec<(i32, i64)>, DbErr> {
+        post_tags::Entity::find()
+            .select_only()
+            .column(post_tags::Column::TagId)
+            .column_as(Expr::col(post_tags::Column::PostId).count(), "post_count")
+            .join(JoinType::InnerJoin, post_tags::Relation::Posts.def())
+            .filter(post_tags::Column::TagId.is_in(tag_ids.iter().copied()))
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .group_by(post_tags::Column::TagId)
+            .into_tuple()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn create(&self, name: &str) -> Result<tags::Model, DbErr> {
+        let tag = tags::ActiveModel {
+            name: Set(name.to_string()),
+            ..Default::default()
+        };
+
+        tag.insert(&self.db_pool).await
+    }
+
+    async fn update(&self, id: i32, name: &str) -> Result<Option<tags::Model>, DbErr> {
+        let Some(tag) = Tags::find_by_id(id).one(&self.db_pool).await? else {
+            return Ok(None);
+        };
+
+        let mut tag: tags::ActiveModel = tag.into();
+        tag.name = Set(name.to_string());
+
+        tag.update(&self.db_pool).await.map(Some)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), DbErr> {
+        let result = Tags::delete_by_id(id).exec(&self.db_pool).await?;
+
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Tag not found".to_owned()));
+        }
+
+        Ok(())
+    }
+}
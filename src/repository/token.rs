@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, Set, TransactionTrait,
+};
+
+use crate::abstract_trait::TokenRepositoryTrait;
+use crate::entities::{refresh_tokens, revoked_tokens, RefreshTokens, RevokedTokens};
+
+pub struct TokenRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl TokenRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TokenRepositoryTrait for TokenRepository {
+    async fn create_refresh_token(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        family_id: &str,
+        expires_at: DateTimeWithTimeZone,
+    ) -> Result<refresh_tokens::Model, DbErr> {
+        let token = refresh_tokens::ActiveModel {
+            user_id: Set(user_id),
+            token_hash: Set(token_hash.to_string()),
+            family_id: Set(family_id.to_string()),
+            expires_at: Set(expires_at),
+            created_at: Set(Utc::now().fixed_offset()),
+            ..Default::default()
+        };
+
+        token.insert(&self.db_pool).await
+    }
+
+    async fn find_refresh_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<refresh_tokens::Model>, DbErr> {
+        RefreshTokens::find()
+            .filter(refresh_tokens::Column::TokenHash.eq(token_hash))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn rotate_refresh_token(
+        &self,
+        current: &refresh_tokens::Model,
+        token_hash: &str,
+        expires_at: DateTimeWithTimeZone,
+    ) -> Result<Option<refresh_tokens::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+        let now = Utc::now().fixed_offset();
+
+        let successor = refresh_tokens::ActiveModel {
+            user_id: Set(current.user_id),
+            token_hash: Set(token_hash.to_string()),
+            family_id: Set(current.family_id.clone()),
+            expires_at: Set(expires_at),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        // Only the request that flips `revoked_at` wins; a concurrent reuse sees zero rows.
+        let revoked = RefreshTokens::update_many()
+            .col_expr(refresh_tokens::Column::RevokedAt, Expr::value(now))
+            .col_expr(refresh_tokens::Column::ReplacedBy, Expr::value(successor.id))
+            .filter(refresh_tokens::Column::Id.eq(current.id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(&txn)
+            .await?;
+
+        if revoked.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        txn.commit().await?;
+
+        Ok(Some(successor))
+    }
+
+    async fn revoke_refresh_token_family(&self, family_id: &str) -> Result<u64, DbErr> {
+        let result = RefreshTokens::update_many()
+            .col_expr(
+                refresh_tokens::Column::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(refresh_tokens::Column::FamilyId.eq(family_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn revoke_access_token(
+        &self,
+        jti: &str,
+        user_id: i32,
+        expires_at: DateTimeWithTimeZone,
+    ) -> Result<(), DbErr> {
+        // Entries past their expiry can never match a valid token again.
+        RevokedTokens::delete_many()
+            .filter(revoked_tokens::Column::ExpiresAt.lt(Utc::now().fixed_offset()))
+            .exec(&self.db_pool)
+            .await?;
+
+        let revoked = revoked_tokens::ActiveModel {
+            jti: Set(jti.to_string()),
+            user_id: Set(user_id),
+            expires_at: Set(expires_at),
+        };
+
+        RevokedTokens::insert(revoked)
+            .on_conflict(
+                OnConflict::column(revoked_tokens::Column::Jti)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn is_access_token_revoked(&self, jti: &str) -> Result<bool, DbErr> {
+        let count = RevokedTokens::find()
+            .filter(revoked_tokens::Column::Jti.eq(jti))
+            .count(&self.db_pool)
+            .await?;
+
+        Ok(count > 0)
+    }
+}
@@ -2,8 +2,8 @@ use sea_orm::{prelude::*, Set};
 use sea_orm::{DatabaseConnection, DbErr};
 use async_trait::async_trait;
 use crate::abstract_trait::UserRepositoryTrait;
-use crate::domain::{CreateUserRequest, UpdateUserRequest};
-use crate::entities::users; 
+use crate::domain::{CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest};
+use crate::entities::{sea_orm_active_enums::Role, users};
 
 pub struct UserRepository {
     db_pool: DatabaseConnection,
@@ -31,6 +31,7 @@ impl UserRepositoryTrait for UserRepository {
             lastname: Set(input.lastname.clone()),
             email: Set(input.email.clone()),
             password: Set(input.password.clone()),
+            role: Set(input.role.unwrap_or(Role::Reader)),
             ..Default::default() 
         };
 
@@ -80,6 +81,23 @@ impl UserRepositoryTrait for UserRepository {
     }
     
 
+    async fn update_role(&self, input: &UpdateUserRoleRequest) -> Result<users::Model, DbErr> {
+        let id = match input.id {
+            Some(id) => id,
+            None => return Err(DbErr::Custom("User ID is required".to_string())),
+        };
+
+        let mut user: users::ActiveModel = users::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await?
+            .ok_or(DbErr::Custom("User not found".to_string()))?
+            .into();
+
+        user.role = Set(input.role);
+
+        user.update(&self.db_pool).await
+    }
+
     async fn delete_user(&self, email: &str) -> Result<(), DbErr> {
         let user: users::ActiveModel = users::Entity::find()
             .filter(users::Column::Email.eq(email))
@@ -1,47 +1,69 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
 use crate::{
-    abstract_trait::{AuthServiceTrait, DynUserRepository},
-    config::{Hashing, JwtConfig},
-    domain::{ApiResponse, CreateUserRequest, ErrorResponse, LoginRequest, RegisterRequest, UserResponse},
+    abstract_trait::{AuthServiceTrait, DynTokenRepository, DynUserRepository},
+    config::{Claims, Hashing, JwtConfig},
+    entities::sea_orm_active_enums::Role,
+    domain::{ApiResponse, CreateUserRequest, LoginRequest, LogoutRequest, RefreshTokenRequest, RegisterRequest, TokenResponse, UserResponse},
     utils::AppError,
 };
 
 pub struct AuthService {
     repository: DynUserRepository,
+    token_repository: DynTokenRepository,
     hashing: Hashing,
     jwt_config: JwtConfig,
 }
 
 impl AuthService {
-    pub fn new(repository: DynUserRepository, hashing: Hashing, jwt_config: JwtConfig) -> Self {
-        Self { repository, hashing, jwt_config }
+    pub fn new(repository: DynUserRepository, token_repository: DynTokenRepository, hashing: Hashing, jwt_config: JwtConfig) -> Self {
+        Self { repository, token_repository, hashing, jwt_config }
+    }
+
+    async fn issue_tokens(&self, user_id: i32, role: Role, family_id: &str) -> Result<TokenResponse, AppError> {
+        let access_token = self.jwt_config.generate_token(user_id as i64, role)?;
+
+        let refresh_token = self.jwt_config.generate_refresh_token();
+        let expires_at = (Utc::now() + self.jwt_config.refresh_token_ttl).fixed_offset();
+
+        self.token_repository
+            .create_refresh_token(user_id, &self.jwt_config.hash_refresh_token(&refresh_token), family_id, expires_at)
+            .await?;
+
+        Ok(self.token_response(access_token, refresh_token))
+    }
+
+    fn token_response(&self, access_token: String, refresh_token: String) -> TokenResponse {
+        TokenResponse {
+            access_token,
+            refresh_token,
+            token_type: "Bearer".to_string(),
+            expires_in: self.jwt_config.access_token_ttl.num_seconds(),
+        }
     }
 }
 
 #[async_trait]
 impl AuthServiceTrait for AuthService {
-    async fn register_user(&self, input: &RegisterRequest) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
-        let exists = self.repository.find_by_email_exists(&input.email).await
-            .map_err(AppError::from)  
-            .map_err(ErrorResponse::from)?; 
+    async fn register_user(&self, input: &RegisterRequest) -> Result<ApiResponse<UserResponse>, AppError> {
+        let exists = self.repository.find_by_email_exists(&input.email).await?;
 
         if exists {
-            return Err(ErrorResponse::from(AppError::EmailAlreadyExists));
+            return Err(AppError::EmailAlreadyExists);
         }
 
-        let hashed_password = self.hashing.hash_password(&input.password).await
-            .map_err(|e| ErrorResponse::from(AppError::HashingError(e)))?;
+        let hashed_password = self.hashing.hash_password(&input.password).await?;
 
         let request = CreateUserRequest {
             firstname: input.firstname.clone(),
             lastname: input.lastname.clone(),
             email: input.email.clone(),
             password: hashed_password,
+            role: None,
         };
 
-        let create_user = self.repository.create_user(&request).await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+        let create_user = self.repository.create_user(&request).await?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
@@ -50,27 +72,96 @@ impl AuthServiceTrait for AuthService {
         })
     }
 
-    async fn login_user(&self, input: &LoginRequest) -> Result<ApiResponse<String>, ErrorResponse> {
-        let user = self.repository.find_by_email(&input.email).await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?
-            .ok_or_else(|| ErrorResponse::from(AppError::NotFound("User not found".to_string())))?;
+    async fn login_user(&self, input: &LoginRequest) -> Result<ApiResponse<TokenResponse>, AppError> {
+        let user = self.repository.find_by_email(&input.email).await?
+            .ok_or(AppError::InvalidCredentials)?;
 
         if self.hashing.compare_password(&user.password, &input.password).await.is_err() {
-            return Err(ErrorResponse::from(AppError::InvalidCredentials));
+            return Err(AppError::InvalidCredentials);
         }
 
-        let token = self.jwt_config.generate_token(user.id as i64)
-            .map_err(ErrorResponse::from)?;
+        // Every login starts a new refresh token family.
+        let tokens = self.issue_tokens(user.id, user.role, &Uuid::new_v4().to_string()).await?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Login successful".to_string(),
-            data: token,
+            data: tokens,
+        })
+    }
+
+    async fn refresh_token(&self, input: &RefreshTokenRequest) -> Result<ApiResponse<TokenResponse>, AppError> {
+        let token_hash = self.jwt_config.hash_refresh_token(&input.refresh_token);
+
+        let current = self.token_repository.find_refresh_token_by_hash(&token_hash).await?
+            .ok_or(AppError::InvalidRefreshToken)?;
+
+        if current.revoked_at.is_some() {
+            // A rotated token is being presented again: assume it was stolen and kill the family.
+            self.token_repository.revoke_refresh_token_family(&current.family_id).await?;
+
+            return Err(AppError::RefreshTokenReuseDetected);
+        }
+
+        if current.expires_at < Utc::now() {
+            return Err(AppError::InvalidRefreshToken);
+        }
+
+        // Re-read the user so role changes take effect on the next access token.
+        let user = self.repository.find_by_id(current.user_id).await?
+            .ok_or(AppError::InvalidRefreshToken)?;
+
+        let refresh_token = self.jwt_config.generate_refresh_token();
+        let expires_at = (Utc::now() + self.jwt_config.refresh_token_ttl).fixed_offset();
+
+        let rotated = self.token_repository
+            .rotate_refresh_token(&current, &self.jwt_config.hash_refresh_token(&refresh_token), expires_at)
+            .await?;
+
+        if rotated.is_none() {
+            self.token_repository.revoke_refresh_token_family(&current.family_id).await?;
+
+            return Err(AppError::RefreshTokenReuseDetected);
+        }
+
+        let access_token = self.jwt_config.generate_token(current.user_id as i64, user.role)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Token refreshed successfully".to_string(),
+            data: self.token_response(access_token, refresh_token),
+        })
+    }
+
+    async fn logout_user(&self, claims: &Claims, input: &LogoutRequest) -> Result<ApiResponse<()>, AppError> {
+        let expires_at = DateTime::<Utc>::from_timestamp(claims.exp as i64, 0)
+            .unwrap_or_else(|| Utc::now() + self.jwt_config.access_token_ttl)
+            .fixed_offset();
+
+        self.token_repository.revoke_access_token(&claims.jti, claims.user_id as i32, expires_at).await?;
+
+        if let Some(refresh_token) = &input.refresh_token {
+            let token_hash = self.jwt_config.hash_refresh_token(refresh_token);
+
+            let current = self.token_repository.find_refresh_token_by_hash(&token_hash).await?;
+
+            if let Some(current) = current.filter(|token| token.user_id as i64 == claims.user_id) {
+                self.token_repository.revoke_refresh_token_family(&current.family_id).await?;
+            }
+        }
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Logout successful".to_string(),
+            data: (),
         })
     }
 
+    async fn is_token_revoked(&self, jti: &str) -> Result<bool, AppError> {
+        self.token_repository.is_access_token_revoked(jti).await.map_err(AppError::from)
+    }
+
     fn verify_token(&self, token: &str) -> Result<i64, AppError> {
-        return self.jwt_config.verify_token(token).map_err(AppError::from);
+        self.jwt_config.verify_token(token)
     }
 }
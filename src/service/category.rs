@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
 use crate::{
     abstract_trait::{CategoryServiceTrait, DynCategoryRepository},
     domain::{
-        ApiResponse, ApiResponsePagination, CategoryResponse, CreateCategoryRequest, ErrorResponse,
-        FindAllCategoryRequest, Pagination, UpdateCategoryRequest,
+        ApiResponse, ApiResponsePagination, CategoryBreadcrumb, CategoryMergeResponse,
+        CategoryResponse, CategoryTreeResponse, CreateCategoryRequest, FindAllCategoryRequest,
+        Pagination, UpdateCategoryRequest,
     },
+    entities::categories,
     utils::AppError,
 };
 use async_trait::async_trait;
@@ -16,6 +20,138 @@ impl CategoryService {
     pub fn new(repository: DynCategoryRepository) -> Self {
         Self { repository }
     }
+
+    async fn validate_parent(&self, parent_id: i32) -> Result<(), AppError> {
+        if self.repository.find_by_id(parent_id).await?.is_none() {
+            return Err(AppError::Validation(format!(
+                "Parent category with id {} does not exist",
+                parent_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Moves everything filed under `source_id` to `target_id`, then deletes `source_id`.
+    async fn merge(
+        &self,
+        source_id: i32,
+        target_id: i32,
+    ) -> Result<CategoryMergeResponse, AppError> {
+        if source_id == target_id {
+            return Err(AppError::Validation(
+                "A category cannot be merged into itself".to_string(),
+            ));
+        }
+
+        if self.repository.find_by_id(source_id).await?.is_none() {
+            return Err(AppError::NotFound(format!(
+                "Category with id {} not found",
+                source_id
+            )));
+        }
+
+        if self.repository.find_by_id(target_id).await?.is_none() {
+            return Err(AppError::Validation(format!(
+                "Target category with id {} does not exist",
+                target_id
+            )));
+        }
+
+        let (posts_moved, revisions_moved, subcategories_moved) = self
+            .repository
+            .merge(source_id, target_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Validation(
+                    "A category cannot be merged into one of its subcategories".to_string(),
+                )
+            })?;
+
+        Ok(CategoryMergeResponse {
+            source_id,
+            target_id,
+            posts_moved,
+            revisions_moved,
+            subcategories_moved,
+        })
+    }
+
+    async fn with_breadcrumbs(
+        &self,
+        categories: Vec<(categories::Model, i64)>,
+    ) -> Result<Vec<CategoryResponse>, AppError> {
+        let ids: Vec<i32> = categories.iter().map(|(category, _)| category.id).collect();
+
+        let by_id: HashMap<i32, categories::Model> = self
+            .repository
+            .find_ancestors(&ids)
+            .await?
+            .into_iter()
+            .map(|category| (category.id, category))
+            .collect();
+
+        Ok(categories
+            .into_iter()
+            .map(|(category, post_count)| {
+                let mut breadcrumbs = Vec::new();
+                let mut current = Some(&category);
+                while let Some(node) = current {
+                    // Cycles cannot be created through the API; stop if one was made by hand.
+                    if breadcrumbs.len() > by_id.len() {
+                        break;
+                    }
+                    breadcrumbs.push(CategoryBreadcrumb::from(node));
+                    current = node.parent_id.and_then(|parent_id| by_id.get(&parent_id));
+                }
+                breadcrumbs.reverse();
+
+                CategoryResponse::new(category, post_count, breadcrumbs)
+            })
+            .collect())
+    }
+
+    async fn with_breadcrumb(
+        &self,
+        category: categories::Model,
+    ) -> Result<CategoryResponse, AppError> {
+        let post_count = self.repository.count_published_posts(category.id).await?;
+        let mut responses = self.with_breadcrumbs(vec![(category, post_count)]).await?;
+
+        Ok(responses.remove(0))
+    }
+
+    /// Nests `categories` under their parents, keeping their order among siblings.
+    fn build_tree(categories: Vec<categories::Model>) -> Vec<CategoryTreeResponse> {
+        let mut children: HashMap<Option<i32>, Vec<categories::Model>> = HashMap::new();
+        for category in categories {
+            children
+                .entry(category.parent_id)
+                .or_default()
+                .push(category);
+        }
+
+        fn attach(
+            parent_id: Option<i32>,
+            children: &mut HashMap<Option<i32>, Vec<categories::Model>>,
+        ) -> Vec<CategoryTreeResponse> {
+            children
+                .remove(&parent_id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|category| CategoryTreeResponse {
+                    children: attach(Some(category.id), children),
+                    id: category.id,
+                    name: category.name,
+                    slug: category.slug,
+                    sort_order: category.sort_order,
+                    parent_id: category.parent_id,
+                })
+                .collect()
+        }
+
+        attach(None, &mut children)
+    }
 }
 
 #[async_trait]
@@ -23,7 +159,7 @@ impl CategoryServiceTrait for CategoryService {
     async fn get_categories(
         &self,
         req: FindAllCategoryRequest,
-    ) -> Result<ApiResponsePagination<Vec<CategoryResponse>>, ErrorResponse> {
+    ) -> Result<ApiResponsePagination<Vec<CategoryResponse>>, AppError> {
         let page = req.page.max(1);
         let page_size = req.page_size.max(1);
         let search = if req.search.is_empty() {
@@ -32,17 +168,11 @@ impl CategoryServiceTrait for CategoryService {
             Some(req.search.clone())
         };
 
-        let (categories, total_items) = self
-            .repository
-            .find_all(page, page_size, search)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+        let (categories, total_items) = self.repository.find_all(page, page_size, search).await?;
 
         let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
 
-        let category_responses: Vec<CategoryResponse> =
-            categories.into_iter().map(CategoryResponse::from).collect();
+        let category_responses = self.with_breadcrumbs(categories).await?;
 
         Ok(ApiResponsePagination {
             status: "success".to_string(),
@@ -53,6 +183,7 @@ impl CategoryServiceTrait for CategoryService {
                 page_size,
                 total_items,
                 total_pages,
+                next_cursor: None,
             },
         })
     }
@@ -60,75 +191,139 @@ impl CategoryServiceTrait for CategoryService {
     async fn get_category(
         &self,
         id: i32,
-    ) -> Result<Option<ApiResponse<CategoryResponse>>, ErrorResponse> {
-        let category = self
-            .repository
-            .find_by_id(id)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+    ) -> Result<Option<ApiResponse<CategoryResponse>>, AppError> {
+        let category = self.repository.find_by_id(id).await?;
 
         if let Some(category) = category {
             Ok(Some(ApiResponse {
                 status: "success".to_string(),
                 message: "Category retrieved successfully".to_string(),
-                data: CategoryResponse::from(category),
+                data: self.with_breadcrumb(category).await?,
             }))
         } else {
-            Err(ErrorResponse::from(AppError::NotFound(format!(
+            Err(AppError::NotFound(format!(
                 "Category with id {} not found",
                 id
-            ))))
+            )))
         }
     }
 
-    async fn create_category(
+    async fn get_category_by_slug(
         &self,
-        input: &CreateCategoryRequest,
-    ) -> Result<ApiResponse<CategoryResponse>, ErrorResponse> {
+        slug: &str,
+    ) -> Result<Option<ApiResponse<CategoryResponse>>, AppError> {
         let category = self
             .repository
-            .create(input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Category '{}' not found", slug)))?;
+
+        Ok(Some(ApiResponse {
+            status: "success".to_string(),
+            message: "Category retrieved successfully".to_string(),
+            data: self.with_breadcrumb(category).await?,
+        }))
+    }
+
+    async fn get_category_tree(&self) -> Result<ApiResponse<Vec<CategoryTreeResponse>>, AppError> {
+        let categories = self.repository.find_tree().await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Category tree retrieved successfully".to_string(),
+            data: Self::build_tree(categories),
+        })
+    }
+
+    async fn create_category(
+        &self,
+        input: &CreateCategoryRequest,
+    ) -> Result<ApiResponse<CategoryResponse>, AppError> {
+        if let Some(parent_id) = input.parent_id {
+            self.validate_parent(parent_id).await?;
+        }
+
+        let category = self.repository.create(input).await?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Category created successfully".to_string(),
-            data: CategoryResponse::from(category),
+            data: self.with_breadcrumb(category).await?,
         })
     }
 
     async fn update_category(
         &self,
         input: &UpdateCategoryRequest,
-    ) -> Result<Option<ApiResponse<CategoryResponse>>, ErrorResponse> {
-        let category = self
-            .repository
-            .update(input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+    ) -> Result<Option<ApiResponse<CategoryResponse>>, AppError> {
+        if let Some(Some(parent_id)) = input.parent_id {
+            self.validate_parent(parent_id).await?;
+        }
+
+        // The repository checks for loops while holding locks on the affected rows.
+        let category = self.repository.update(input).await?.ok_or_else(|| {
+            AppError::Validation(
+                "A category cannot be moved below itself or one of its subcategories".to_string(),
+            )
+        })?;
 
         Ok(Some(ApiResponse {
             status: "success".to_string(),
             message: "Category updated successfully".to_string(),
-            data: CategoryResponse::from(category),
+            data: self.with_breadcrumb(category).await?,
         }))
     }
 
-    async fn delete_category(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
-        self.repository
-            .delete(id)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+    async fn delete_category(
+        &self,
+        id: i32,
+        reassign_to: Option<i32>,
+    ) -> Result<ApiResponse<Option<CategoryMergeResponse>>, AppError> {
+        if let Some(target_id) = reassign_to {
+            let report = self.merge(id, target_id).await?;
+
+            return Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Category deleted and its posts reassigned successfully".to_string(),
+                data: Some(report),
+            });
+        }
+
+        let (posts, revisions, subcategories) = self.repository.delete(id).await?;
+
+        if posts > 0 || revisions > 0 {
+            return Err(AppError::Conflict(format!(
+                "Category is still used by {} post(s) and {} post revision(s); \
+                 pass reassign_to to move them to another category",
+                posts, revisions
+            )));
+        }
+
+        if subcategories > 0 {
+            return Err(AppError::Conflict(
+                "Category has subcategories; move or delete them first, or pass reassign_to"
+                    .to_string(),
+            ));
+        }
 
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Category deleted successfully".to_string(),
-            data: (),
+            data: None,
+        })
+    }
+
+    async fn merge_category(
+        &self,
+        source_id: i32,
+        target_id: i32,
+    ) -> Result<ApiResponse<CategoryMergeResponse>, AppError> {
+        let report = self.merge(source_id, target_id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Categories merged successfully".to_string(),
+            data: report,
         })
     }
 }
@@ -1,20 +1,154 @@
-use crate::{abstract_trait::{CommentServiceTrait, DynCommentRepository, }, domain::{ApiResponse, CommentResponse, CreateCommentRequest, ErrorResponse, UpdateCommentRequest},  utils::AppError};
+use std::collections::HashMap;
+
+use crate::{abstract_trait::{CommentServiceTrait, DynCommentFilterService, DynCommentRepository, DynPostsRepository}, config::Claims, entities::{comments, sea_orm_active_enums::{CommentStatus, FilterAction, Role}}, domain::{ApiResponse, ApiResponsePagination, CommentResponse, CommentSettingsResponse, CommentTreeResponse, CreateCommentRequest, FindModerationQueueRequest, FindPostCommentsRequest, ModerateCommentsRequest, Pagination, UpdateCommentRequest, UpdateCommentSettingsRequest},  service::PostService, utils::{AppError, Permission}};
 use async_trait::async_trait;
+use tracing::info;
 
 pub struct CommentService {
     repository: DynCommentRepository,
+    post_repository: DynPostsRepository,
+    filter_service: DynCommentFilterService,
+    max_depth: i32,
+    require_approval: bool,
 }
 
 impl CommentService {
-    pub fn new(repository: DynCommentRepository) -> Self {
-        Self { repository }
+    /// `max_depth` is how deeply replies may nest; 0 allows top-level comments only.
+    /// `require_approval` holds every new comment on every post for moderation.
+    pub fn new(repository: DynCommentRepository, post_repository: DynPostsRepository, filter_service: DynCommentFilterService, max_depth: i32, require_approval: bool) -> Self {
+        Self { repository, post_repository, filter_service, max_depth, require_approval }
+    }
+
+    /// Moderators go live straight away. Comments the filters object to are
+    /// held or filed as spam. Everyone else waits for approval when the site or
+    /// the post requires it, and otherwise until a first comment of theirs has
+    /// been approved.
+    async fn initial_status(&self, claims: &Claims, input: &CreateCommentRequest, require_approval: bool) -> Result<CommentStatus, AppError> {
+        if claims.role.has_permission(Permission::ModerateComments) {
+            return Ok(CommentStatus::Approved);
+        }
+
+        if let Some(verdict) = self.filter_service.evaluate(claims.user_id as i32, input).await? {
+            info!("Comment by user {} on post {} filtered ({:?}): {}", claims.user_id, input.id_post_comment, verdict.action, verdict.reason);
+
+            return Ok(match verdict.action {
+                FilterAction::Hold => CommentStatus::Pending,
+                FilterAction::Spam => CommentStatus::Spam,
+            });
+        }
+
+        self.review_status(claims, require_approval).await
+    }
+
+    /// Holds the comment when the site or the post requires approval, or when
+    /// its author has no approved comment yet.
+    async fn review_status(&self, claims: &Claims, require_approval: bool) -> Result<CommentStatus, AppError> {
+        if self.require_approval || require_approval {
+            return Ok(CommentStatus::Pending);
+        }
+
+        let approved = self.repository.count_approved_by_user(claims.user_id as i32).await?;
+
+        Ok(if approved > 0 { CommentStatus::Approved } else { CommentStatus::Pending })
+    }
+
+    /// Status after `comment` is edited to `text`. Edits by moderators and edits
+    /// that keep the text leave it alone, and an edit never lifts a rejection or
+    /// a spam verdict; anything else runs through the filters and is reviewed
+    /// again like a new comment.
+    async fn edited_status(&self, claims: &Claims, comment: &comments::Model, text: &str) -> Result<CommentStatus, AppError> {
+        if claims.role.has_permission(Permission::ModerateComments)
+            || comment.comment == text
+            || matches!(comment.status, CommentStatus::Rejected | CommentStatus::Spam)
+        {
+            return Ok(comment.status);
+        }
+
+        let require_approval = self.post_repository.get_post(comment.id_post_comment).await?
+            .is_some_and(|post| post.comments_require_approval);
+
+        let edit = CreateCommentRequest {
+            id_post_comment: comment.id_post_comment,
+            comment: text.to_string(),
+            parent_id: comment.parent_id,
+            website: None,
+        };
+
+        self.initial_status(claims, &edit, require_approval).await
+    }
+
+    async fn validate_parent(&self, input: &CreateCommentRequest) -> Result<(), AppError> {
+        let Some(parent_id) = input.parent_id else {
+            return Ok(());
+        };
+
+        // Replies to comments readers cannot see are refused as well
+        let parent = self.repository.find_by_id(parent_id).await?
+            .filter(|parent| parent.status == CommentStatus::Approved)
+            .ok_or_else(|| AppError::Validation(format!("Parent comment with id {} does not exist", parent_id)))?;
+
+        if parent.id_post_comment != input.id_post_comment {
+            return Err(AppError::Validation("A reply must belong to the same post as its parent comment".to_string()));
+        }
+
+        let parent_depth = self.repository.find_depth(parent_id).await?.unwrap_or(0);
+        if parent_depth + 1 > self.max_depth {
+            return Err(AppError::Validation(format!("Replies can be nested at most {} level(s) deep", self.max_depth)));
+        }
+
+        Ok(())
+    }
+
+    /// Nests `comments`, which come parents first, under their parents.
+    fn build_tree(comments: Vec<comments::Model>) -> Vec<CommentTreeResponse> {
+        let mut children: HashMap<Option<i32>, Vec<comments::Model>> = HashMap::new();
+        for comment in comments {
+            children.entry(comment.parent_id).or_default().push(comment);
+        }
+
+        fn attach(
+            parent_id: Option<i32>,
+            depth: i32,
+            children: &mut HashMap<Option<i32>, Vec<comments::Model>>,
+        ) -> Vec<CommentTreeResponse> {
+            children
+                .remove(&parent_id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|comment| CommentTreeResponse {
+                    replies: attach(Some(comment.id), depth + 1, children),
+                    id: comment.id,
+                    id_post_comment: comment.id_post_comment,
+                    user_id: comment.user_id,
+                    parent_id: comment.parent_id,
+                    comment: comment.comment,
+                    depth,
+                })
+                .collect()
+        }
+
+        attach(None, 0, &mut children)
+    }
+
+    async fn ensure_owner(&self, claims: &Claims, id: i32) -> Result<comments::Model, AppError> {
+        let comment = self.repository.find_by_id(id).await?
+            .ok_or_else(|| AppError::NotFound(format!("Comment with id {} not found", id)))?;
+
+        // Legacy comments without an author can only be changed by admins
+        let is_allowed = comment.user_id.map_or(claims.role == Role::Admin, |owner_id| claims.is_owner_or_admin(owner_id));
+
+        if !is_allowed {
+            return Err(AppError::Forbidden("Only the author or an admin can modify this comment".to_string()));
+        }
+
+        Ok(comment)
     }
 }
 
 #[async_trait]
 impl CommentServiceTrait for CommentService {
-    async fn get_comments(&self) -> Result<ApiResponse<Vec<CommentResponse>>, ErrorResponse> {
-        let comments = self.repository.find_all().await .map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn get_comments(&self) -> Result<ApiResponse<Vec<CommentResponse>>, AppError> {
+        let comments = self.repository.find_approved().await?;
         
         let response = comments.into_iter().map(|comment| {
             CommentResponse::from(comment)
@@ -27,8 +161,13 @@ impl CommentServiceTrait for CommentService {
         })
     }
 
-    async fn get_comment(&self, id: i32) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> {
-        let comment = self.repository.find_by_id(id).await .map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn get_comment(&self, claims: &Claims, id: i32) -> Result<Option<ApiResponse<CommentResponse>>, AppError> {
+        // Held comments stay visible to their author and to moderators
+        let comment = self.repository.find_by_id(id).await?.filter(|comment| {
+            comment.status == CommentStatus::Approved
+                || comment.user_id == Some(claims.user_id as i32)
+                || claims.role.has_permission(Permission::ModerateComments)
+        });
 
         
         
@@ -39,32 +178,99 @@ impl CommentServiceTrait for CommentService {
                 data: CommentResponse::from(comment),
             }))
         }else{
-            Err(ErrorResponse::from(AppError::NotFound(format!("Comment with id {} not found", id))))
+            Err(AppError::NotFound(format!("Comment with id {} not found", id)))
         }
     }
 
-    async fn create_comment(&self, input: &CreateCommentRequest) -> Result<ApiResponse<CommentResponse>, ErrorResponse> {
-        let comment = self.repository.create(input).await .map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn get_post_comments(&self, post_id: i32, req: &FindPostCommentsRequest) -> Result<ApiResponsePagination<Vec<CommentResponse>>, AppError> {
+        let page_size = req.page_size.max(1);
+
+        let (mut comments, total_items) = self.repository.find_by_post(post_id, req).await?;
+
+        let next_cursor = if comments.len() > page_size as usize {
+            comments.truncate(page_size as usize);
+            comments.last().map(|comment| comment.id)
+        } else {
+            None
+        };
+
+        let preceding = match req.cursor {
+            Some(cursor) => self.repository.count_through_cursor(post_id, req, cursor).await?,
+            None => 0,
+        };
+
+        let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+        Ok(ApiResponsePagination {
+            status: "success".to_string(),
+            message: "Comments retrieved successfully".to_string(),
+            data: comments.into_iter().map(CommentResponse::from).collect(),
+            pagination: Pagination {
+                page: (preceding / page_size as i64) as i32 + 1,
+                page_size,
+                total_items,
+                total_pages,
+                next_cursor,
+            },
+        })
+    }
+
+    async fn get_comment_tree(&self, post_id: i32) -> Result<ApiResponse<Vec<CommentTreeResponse>>, AppError> {
+        let comments = self.repository.find_thread(post_id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Comment tree retrieved successfully".to_string(),
+            data: Self::build_tree(comments),
+        })
+    }
+
+    async fn create_comment(&self, claims: &Claims, input: &CreateCommentRequest) -> Result<ApiResponse<CommentResponse>, AppError> {
+        // Posts the caller cannot view are treated as missing rather than revealed
+        let post = self.post_repository.get_post(input.id_post_comment).await?
+            .filter(|post| PostService::can_view(Some(claims), post))
+            .ok_or_else(|| AppError::Validation(format!("Post with id {} does not exist", input.id_post_comment)))?;
+
+        self.validate_parent(input).await?;
+
+        let status = self.initial_status(claims, input, post.comments_require_approval).await?;
+
+        let comment = self.repository.create(claims.user_id as i32, input, status).await?;
         
         Ok(ApiResponse {
             status: "success".to_string(),
-            message: "Comment created successfully".to_string(),
+            message: if status == CommentStatus::Approved {
+                "Comment created successfully".to_string()
+            } else {
+                "Comment submitted for moderation".to_string()
+            },
             data: CommentResponse::from(comment),
         })
     }
 
-    async fn update_comment(&self, input: &UpdateCommentRequest) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> {
-        let comment = self.repository.update(input).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn update_comment(&self, claims: &Claims, input: &UpdateCommentRequest) -> Result<Option<ApiResponse<CommentResponse>>, AppError> {
+        let id = input.id.ok_or_else(|| AppError::Validation("Comment ID is required".to_string()))?;
+
+        let existing = self.ensure_owner(claims, id).await?;
+        let status = self.edited_status(claims, &existing, &input.comment).await?;
+
+        let comment = self.repository.update(input, status).await?;
         
         Ok(Some(ApiResponse {
             status: "success".to_string(),
-            message: "Comment updated successfully".to_string(),
+            message: if status == CommentStatus::Approved || status == existing.status {
+                "Comment updated successfully".to_string()
+            } else {
+                "Comment updated and submitted for moderation".to_string()
+            },
             data: CommentResponse::from(comment),
         }))
     }
 
-    async fn delete_comment(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
-        self.repository.delete(id).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn delete_comment(&self, claims: &Claims, id: i32) -> Result<ApiResponse<()>, AppError> {
+        self.ensure_owner(claims, id).await?;
+
+        self.repository.delete(id).await?;
         
         Ok(ApiResponse {
             status: "success".to_string(),
@@ -72,4 +278,58 @@ impl CommentServiceTrait for CommentService {
             data: (),
         })
     }
+
+    async fn get_moderation_queue(&self, req: &FindModerationQueueRequest) -> Result<ApiResponsePagination<Vec<CommentResponse>>, AppError> {
+        let (comments, total_items) = self.repository.find_by_status(req).await?;
+
+        let total_pages = (total_items as f64 / req.page_size as f64).ceil() as i32;
+
+        Ok(ApiResponsePagination {
+            status: "success".to_string(),
+            message: "Moderation queue retrieved successfully".to_string(),
+            data: comments.into_iter().map(CommentResponse::from).collect(),
+            pagination: Pagination {
+                page: req.page,
+                page_size: req.page_size,
+                total_items,
+                total_pages,
+                next_cursor: None,
+            },
+        })
+    }
+
+    async fn moderate_comments(&self, input: &ModerateCommentsRequest) -> Result<ApiResponse<Vec<CommentResponse>>, AppError> {
+        let comments = self.repository.update_status(&input.ids, input.status).await?;
+
+        if comments.is_empty() {
+            return Err(AppError::NotFound("None of the listed comments exist".to_string()));
+        }
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: format!("{} comment(s) moderated", comments.len()),
+            data: comments.into_iter().map(CommentResponse::from).collect(),
+        })
+    }
+
+    async fn update_comment_settings(&self, claims: &Claims, post_id: i32, input: &UpdateCommentSettingsRequest) -> Result<ApiResponse<CommentSettingsResponse>, AppError> {
+        let post = self.post_repository.get_post(post_id).await?
+            .ok_or_else(|| AppError::NotFound(format!("Post with id {} not found", post_id)))?;
+
+        if post.user_id != claims.user_id as i32 && !claims.role.has_permission(Permission::ModerateComments) {
+            return Err(AppError::Forbidden("Only the post's author or a moderator can change its comment settings".to_string()));
+        }
+
+        let post = self.post_repository.set_comments_require_approval(post_id, input.require_approval).await?
+            .ok_or_else(|| AppError::NotFound(format!("Post with id {} not found", post_id)))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Comment settings updated successfully".to_string(),
+            data: CommentSettingsResponse {
+                post_id: post.id,
+                require_approval: post.comments_require_approval,
+            },
+        })
+    }
 }
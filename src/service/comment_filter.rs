@@ -0,0 +1,194 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use async_trait::async_trait;
+use tracing::warn;
+
+use crate::{
+    abstract_trait::{
+        CommentFilterServiceTrait, DynCommentFilter, DynCommentFilterRepository, FilterVerdict,
+    },
+    comment_filter::compile_rule,
+    domain::{
+        ApiResponse, CommentFilterRuleResponse, CommentFilterStatsResponse,
+        CreateCommentFilterRuleRequest, CreateCommentRequest, UpdateCommentFilterRuleRequest,
+    },
+    entities::sea_orm_active_enums::{FilterAction, FilterRuleKind},
+    utils::AppError,
+};
+
+/// Kept in memory, so they start from zero on every restart and each instance
+/// counts only the comments it checked itself.
+#[derive(Default)]
+struct FilterCounters {
+    checked: AtomicU64,
+    held: AtomicU64,
+    spam: AtomicU64,
+    errors: AtomicU64,
+}
+
+pub struct CommentFilterService {
+    repository: DynCommentFilterRepository,
+    filters: Vec<(DynCommentFilter, FilterCounters)>,
+}
+
+impl CommentFilterService {
+    /// `filters` run in the given order on every new comment.
+    pub fn new(repository: DynCommentFilterRepository, filters: Vec<DynCommentFilter>) -> Self {
+        Self {
+            repository,
+            filters: filters
+                .into_iter()
+                .map(|filter| (filter, FilterCounters::default()))
+                .collect(),
+        }
+    }
+
+    fn validate_pattern(kind: FilterRuleKind, pattern: &str) -> Result<(), AppError> {
+        if pattern.trim().is_empty() {
+            return Err(AppError::Validation(
+                "Rule pattern must not be blank".to_string(),
+            ));
+        }
+
+        compile_rule(kind, pattern)
+            .map(|_| ())
+            .map_err(|e| AppError::Validation(format!("Invalid rule pattern: {}", e)))
+    }
+
+    async fn rules_changed(&self) {
+        for (filter, _) in &self.filters {
+            filter.rules_changed().await;
+        }
+    }
+}
+
+#[async_trait]
+impl CommentFilterServiceTrait for CommentFilterService {
+    async fn evaluate(
+        &self,
+        user_id: i32,
+        input: &CreateCommentRequest,
+    ) -> Result<Option<FilterVerdict>, AppError> {
+        let mut strictest: Option<FilterVerdict> = None;
+
+        // Every filter runs, even after a spam verdict, so the counters show
+        // what each one catches on its own.
+        for (filter, counters) in &self.filters {
+            counters.checked.fetch_add(1, Ordering::Relaxed);
+
+            let verdict = match filter.check(user_id, input).await {
+                Ok(verdict) => verdict,
+                Err(e) => {
+                    // A broken filter sends comments to moderators rather than straight out.
+                    warn!("Comment filter {} failed: {}", filter.name(), e);
+                    counters.errors.fetch_add(1, Ordering::Relaxed);
+                    Some(FilterVerdict {
+                        action: FilterAction::Hold,
+                        reason: format!("{} filter failed", filter.name()),
+                    })
+                }
+            };
+
+            let Some(verdict) = verdict else {
+                continue;
+            };
+
+            match verdict.action {
+                FilterAction::Hold => counters.held.fetch_add(1, Ordering::Relaxed),
+                FilterAction::Spam => counters.spam.fetch_add(1, Ordering::Relaxed),
+            };
+
+            let stricter = strictest.as_ref().is_none_or(|current| {
+                current.action == FilterAction::Hold && verdict.action == FilterAction::Spam
+            });
+            if stricter {
+                strictest = Some(FilterVerdict {
+                    reason: format!("{}: {}", filter.name(), verdict.reason),
+                    ..verdict
+                });
+            }
+        }
+
+        Ok(strictest)
+    }
+
+    async fn get_stats(&self) -> ApiResponse<Vec<CommentFilterStatsResponse>> {
+        ApiResponse {
+            status: "success".to_string(),
+            message: "Comment filter statistics retrieved successfully".to_string(),
+            data: self
+                .filters
+                .iter()
+                .map(|(filter, counters)| CommentFilterStatsResponse {
+                    filter: filter.name().to_string(),
+                    checked: counters.checked.load(Ordering::Relaxed),
+                    held: counters.held.load(Ordering::Relaxed),
+                    spam: counters.spam.load(Ordering::Relaxed),
+                    errors: counters.errors.load(Ordering::Relaxed),
+                })
+                .collect(),
+        }
+    }
+
+    async fn get_rules(&self) -> Result<ApiResponse<Vec<CommentFilterRuleResponse>>, AppError> {
+        let rules = self.repository.find_all().await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Comment filter rules retrieved successfully".to_string(),
+            data: rules
+                .into_iter()
+                .map(CommentFilterRuleResponse::from)
+                .collect(),
+        })
+    }
+
+    async fn create_rule(
+        &self,
+        input: &CreateCommentFilterRuleRequest,
+    ) -> Result<ApiResponse<CommentFilterRuleResponse>, AppError> {
+        Self::validate_pattern(input.kind, &input.pattern)?;
+
+        let rule = self.repository.create(input).await?;
+        self.rules_changed().await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Comment filter rule created successfully".to_string(),
+            data: CommentFilterRuleResponse::from(rule),
+        })
+    }
+
+    async fn update_rule(
+        &self,
+        input: &UpdateCommentFilterRuleRequest,
+    ) -> Result<ApiResponse<CommentFilterRuleResponse>, AppError> {
+        let id = input
+            .id
+            .ok_or_else(|| AppError::BadRequest("Rule ID is required".to_string()))?;
+
+        Self::validate_pattern(input.kind, &input.pattern)?;
+
+        let rule = self.repository.update(id, input).await?.ok_or_else(|| {
+            AppError::NotFound(format!("Comment filter rule with id {} not found", id))
+        })?;
+        self.rules_changed().await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Comment filter rule updated successfully".to_string(),
+            data: CommentFilterRuleResponse::from(rule),
+        })
+    }
+
+    async fn delete_rule(&self, id: i32) -> Result<ApiResponse<()>, AppError> {
+        self.repository.delete(id).await?;
+        self.rules_changed().await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Comment filter rule deleted successfully".to_string(),
+            data: (),
+        })
+    }
+}
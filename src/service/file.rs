@@ -1,132 +1,263 @@
 use async_trait::async_trait;
-use axum::{extract::Multipart, http::StatusCode, Json};
 use chrono::Local;
-use std::{collections::HashMap, fs, path::Path};
-use tokio::{fs::File, io::AsyncWriteExt};
+use image::ImageFormat;
+use sea_orm::{DbErr, SqlErr};
+use sha2::{Digest, Sha256};
+use std::{fs::File, io, path::Path};
+use tokio::fs;
 use uuid::Uuid;
 
 use crate::{
-    abstract_trait::FileServiceTrait,
-    domain::{DeleteResponse, UploadResponse},
+    abstract_trait::{
+        DynImageVariantRepository, DynMediaRepository, DynStorageBackend, FileServiceTrait,
+    },
+    domain::{CreateMediaRequest, DeleteResponse, UploadResponse, UploadedFile},
+    entities::media,
+    utils::{
+        render_variants, sanitize_image, AppError, ImageLimits, RenderedVariant, VariantSettings,
+    },
 };
 
-pub struct FileService {}
+pub struct FileService {
+    storage: DynStorageBackend,
+    variant_repository: DynImageVariantRepository,
+    media_repository: DynMediaRepository,
+    limits: ImageLimits,
+    variants: VariantSettings,
+}
 
 impl FileService {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(
+        storage: DynStorageBackend,
+        variant_repository: DynImageVariantRepository,
+        media_repository: DynMediaRepository,
+        limits: ImageLimits,
+        variants: VariantSettings,
+    ) -> Self {
+        Self {
+            storage,
+            variant_repository,
+            media_repository,
+            limits,
+            variants,
+        }
     }
 
-    fn get_extension_from_mime(&self, mime_type: &str) -> Option<&'static str> {
-        let mime_map: HashMap<&str, &str> =
-            HashMap::from([("image/jpeg", "jpg"), ("image/png", "png")]);
-        mime_map.get(mime_type).copied()
+    /// Stores the original under `key` followed by its variants. A failure part
+    /// way through removes whatever was already stored.
+    async fn store_image(
+        &self,
+        key: &str,
+        source: &Path,
+        format: ImageFormat,
+        variants: &[RenderedVariant],
+    ) -> Result<(), AppError> {
+        self.storage.put(key, source, format.to_mime_type()).await?;
+
+        if let Err(e) = self.store_variants(key, variants).await {
+            let _ = self.delete_image(key).await;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    async fn store_variants(
+        &self,
+        image_key: &str,
+        variants: &[RenderedVariant],
+    ) -> Result<(), AppError> {
+        let (stem, _) = image_key.rsplit_once('.').unwrap_or((image_key, ""));
+
+        for variant in variants {
+            let key = format!(
+                "{}-{}w.{}",
+                stem,
+                variant.width,
+                variant.format.extensions_str()[0]
+            );
+            let content_type = variant.format.to_mime_type();
+
+            self.storage.put(&key, &variant.path, content_type).await?;
+            self.variant_repository
+                .create(
+                    image_key,
+                    &key,
+                    variant.width as i32,
+                    variant.height as i32,
+                    content_type,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn upload_response(
+        &self,
+        media: media::Model,
+        deduplicated: bool,
+    ) -> Result<UploadResponse, AppError> {
+        let url = self.storage.url(&media.path).await?;
+
+        Ok(UploadResponse {
+            message: "File uploaded successfully".to_string(),
+            file_name: media.path,
+            url,
+            media_id: media.id,
+            deduplicated,
+        })
     }
 }
 
+/// Hex SHA-256 of the file as uploaded, before it is re-encoded.
+fn content_hash(path: &Path) -> Result<String, AppError> {
+    let mut file = File::open(path)
+        .map_err(|e| AppError::StorageError(format!("Failed to open upload: {}", e)))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)
+        .map_err(|e| AppError::StorageError(format!("Failed to read upload: {}", e)))?;
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn is_unique_violation(err: &DbErr) -> bool {
+    matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_)))
+}
+
 #[async_trait]
 impl FileServiceTrait for FileService {
     async fn upload_image(
         &self,
-        upload_dir: &str,
-        original_filename: String,
-        content_type: String,
-        file_data: Vec<u8>,
-    ) -> Result<Json<UploadResponse>, (StatusCode, Json<UploadResponse>)> {
-        if file_data.is_empty() {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(UploadResponse {
-                    message: "File is empty or invalid".to_string(),
-                    file_name: "".to_string(),
-                    file_path: "".to_string(),
-                }),
-            ));
+        file: UploadedFile,
+        user_id: i32,
+    ) -> Result<UploadResponse, AppError> {
+        if file.size == 0 {
+            return Err(AppError::BadRequest("File is empty or invalid".to_string()));
+        }
+
+        let path = file.path.clone();
+        let hash = tokio::task::spawn_blocking(move || content_hash(&path))
+            .await
+            .map_err(|e| AppError::StorageError(format!("Hashing failed: {}", e)))??;
+
+        // Identical bytes decode to the identical image, so skip the work.
+        if let Some(existing) = self.media_repository.find_by_hash(&hash).await? {
+            return self.upload_response(existing, true).await;
         }
 
+        // The client's content type is ignored: the stored name and type come
+        // from the decoded image.
+        let path = file.path.clone();
+        let limits = self.limits.clone();
+        let settings = self.variants.clone();
+        let (format, width, height, variants) = tokio::task::spawn_blocking(move || {
+            let image = sanitize_image(&path, &limits)?;
+            let variants = render_variants(&image, &settings)?;
+            Ok::<_, AppError>((
+                image.format,
+                image.image.width(),
+                image.image.height(),
+                variants,
+            ))
+        })
+        .await
+        .map_err(|e| AppError::StorageError(format!("Image processing failed: {}", e)))??;
+
+        let size = fs::metadata(&file.path)
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to stat upload: {}", e)))?
+            .len();
+
         let today = Local::now().format("%Y-%m-%d").to_string();
-        let unique_id = Uuid::new_v4();
-        let mut saved_file_name = format!("{}", unique_id);
-
-        if let Some(ext) = Path::new(&original_filename).extension() {
-            saved_file_name.push('.');
-            saved_file_name.push_str(ext.to_str().unwrap());
-        } else if let Some(ext) = self.get_extension_from_mime(&content_type) {
-            saved_file_name.push('.');
-            saved_file_name.push_str(ext);
+        let extension = format.extensions_str()[0];
+        let key = format!("{}/{}.{}", today, Uuid::new_v4(), extension);
+
+        let stored = self.store_image(&key, &file.path, format, &variants).await;
+        for variant in &variants {
+            let _ = fs::remove_file(&variant.path).await;
         }
+        stored?;
 
-        let folder_path = Path::new(upload_dir).join(today);
-        if !folder_path.exists() {
-            fs::create_dir_all(&folder_path).map_err(|_| {
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(UploadResponse {
-                        message: "Failed to create upload directory".to_string(),
-                        file_name: "".to_string(),
-                        file_path: "".to_string(),
-                    }),
-                )
-            })?;
+        let input = CreateMediaRequest {
+            hash: hash.clone(),
+            path: key.clone(),
+            file_name: file.file_name.clone(),
+            mime: format.to_mime_type().to_string(),
+            size: size as i64,
+            width: width as i32,
+            height: height as i32,
+            user_id,
+        };
+
+        match self.media_repository.create(&input).await {
+            Ok(media) => self.upload_response(media, false).await,
+            Err(e) => {
+                let _ = self.delete_image(&key).await;
+
+                // Someone stored the same bytes while we were processing them.
+                if is_unique_violation(&e) {
+                    if let Some(existing) = self.media_repository.find_by_hash(&hash).await? {
+                        return self.upload_response(existing, true).await;
+                    }
+                }
+
+                Err(e.into())
+            }
         }
+    }
 
-        let file_path = folder_path.join(&saved_file_name);
-
-        let mut file = File::create(&file_path).await.map_err(|_| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(UploadResponse {
-                    message: "Failed to save file".to_string(),
-                    file_name: "".to_string(),
-                    file_path: "".to_string(),
-                }),
-            )
-        })?;
-
-        file.write(&file_data).await.map_err(|_| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(UploadResponse {
-                    message: "Failed to write file".to_string(),
-                    file_name: "".to_string(),
-                    file_path: "".to_string(),
-                }),
-            )
-        })?;
-
-        Ok(Json(UploadResponse {
-            message: "File uploaded successfully".to_string(),
-            file_name: saved_file_name,
-            file_path: file_path.to_string_lossy().to_string(),
-        }))
+    async fn delete_image(&self, file_name: &str) -> Result<DeleteResponse, AppError> {
+        if !self.storage.exists(file_name).await? {
+            return Err(AppError::NotFound("File not found".to_string()));
+        }
+
+        let variants = self
+            .variant_repository
+            .find_by_image_keys(&[file_name.to_string()])
+            .await?;
+        for variant in &variants {
+            if self.storage.exists(&variant.key).await? {
+                self.storage.delete(&variant.key).await?;
+            }
+        }
+        self.variant_repository
+            .delete_by_image_key(file_name)
+            .await?;
+
+        self.storage.delete(file_name).await?;
+
+        Ok(DeleteResponse {
+            message: "File deleted successfully".to_string(),
+        })
     }
 
-    async fn delete_image(
-        &self,
-        upload_dir: &str,
-        file_name: &str,
-    ) -> Result<Json<DeleteResponse>, (StatusCode, Json<DeleteResponse>)> {
-        let file_path = Path::new(upload_dir).join(file_name);
-
-        if file_path.exists() {
-            tokio::fs::remove_file(&file_path).await.map_err(|_| {
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(DeleteResponse {
-                        message: "Failed to delete file".to_string(),
-                    }),
-                )
-            })?;
-            Ok(Json(DeleteResponse {
-                message: "File deleted successfully".to_string(),
-            }))
-        } else {
-            Err((
-                StatusCode::NOT_FOUND,
-                Json(DeleteResponse {
-                    message: "File not found".to_string(),
-                }),
-            ))
+    async fn delete_media(&self, id: i32) -> Result<DeleteResponse, AppError> {
+        let media = self
+            .media_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Media with id {} not found", id)))?;
+
+        let references = self.media_repository.count_references(id).await?;
+        if references > 0 {
+            return Err(AppError::Conflict(format!(
+                "Media with id {} is still used by {} post(s) or revision(s)",
+                id, references
+            )));
+        }
+
+        // The row goes first: the foreign keys refuse it if a post picked the
+        // media up in the meantime, and the files are only removed afterwards.
+        self.media_repository.delete(id).await?;
+
+        match self.delete_image(&media.path).await {
+            Ok(_) | Err(AppError::NotFound(_)) => {}
+            Err(e) => return Err(e),
         }
+
+        Ok(DeleteResponse {
+            message: "Media deleted successfully".to_string(),
+        })
     }
 }
@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+
+use crate::{
+    abstract_trait::{DynFileService, DynMediaRepository, DynStorageBackend, MediaServiceTrait},
+    config::Claims,
+    domain::{ApiResponse, ApiResponsePagination, FindAllMediaRequest, MediaResponse, Pagination},
+    entities::{media, sea_orm_active_enums::Role},
+    utils::AppError,
+};
+
+pub struct MediaService {
+    repository: DynMediaRepository,
+    file_service: DynFileService,
+    storage: DynStorageBackend,
+}
+
+impl MediaService {
+    pub fn new(
+        repository: DynMediaRepository,
+        file_service: DynFileService,
+        storage: DynStorageBackend,
+    ) -> Self {
+        Self {
+            repository,
+            file_service,
+            storage,
+        }
+    }
+
+    async fn to_response(&self, media: media::Model) -> Result<MediaResponse, AppError> {
+        let url = self.storage.url(&media.path).await?;
+
+        Ok(MediaResponse::new(media, url))
+    }
+}
+
+#[async_trait]
+impl MediaServiceTrait for MediaService {
+    async fn get_media_list(
+        &self,
+        req: FindAllMediaRequest,
+    ) -> Result<ApiResponsePagination<Vec<MediaResponse>>, AppError> {
+        let page = req.page.max(1);
+        let page_size = req.page_size.max(1);
+        let search = Some(req.search.trim().to_string()).filter(|search| !search.is_empty());
+        let mime = req
+            .mime
+            .map(|mime| mime.trim().to_lowercase())
+            .filter(|mime| !mime.is_empty());
+
+        let (media, total_items) = self
+            .repository
+            .find_all(page, page_size, search, mime)
+            .await?;
+
+        let mut data = Vec::with_capacity(media.len());
+        for item in media {
+            data.push(self.to_response(item).await?);
+        }
+
+        let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+        Ok(ApiResponsePagination {
+            status: "success".to_string(),
+            message: "Media retrieved successfully".to_string(),
+            data,
+            pagination: Pagination {
+                page,
+                page_size,
+                total_items,
+                total_pages,
+                next_cursor: None,
+            },
+        })
+    }
+
+    async fn get_media(&self, id: i32) -> Result<ApiResponse<MediaResponse>, AppError> {
+        let media = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Media with id {} not found", id)))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Media retrieved successfully".to_string(),
+            data: self.to_response(media).await?,
+        })
+    }
+
+    async fn delete_media(&self, claims: &Claims, id: i32) -> Result<ApiResponse<()>, AppError> {
+        let media = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Media with id {} not found", id)))?;
+
+        // Media whose uploader was deleted can only be removed by admins
+        let is_allowed = media
+            .user_id
+            .map_or(claims.role == Role::Admin, |owner_id| {
+                claims.is_owner_or_admin(owner_id)
+            });
+
+        if !is_allowed {
+            return Err(AppError::Forbidden(
+                "Only the uploader or an admin can delete this media".to_string(),
+            ));
+        }
+
+        self.file_service.delete_media(id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Media deleted successfully".to_string(),
+            data: (),
+        })
+    }
+}
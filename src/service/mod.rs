@@ -1,13 +1,23 @@
 mod auth;
 mod category;
 mod comment;
+mod comment_filter;
 mod file;
+mod media;
 mod posts;
+mod scheduler;
+mod tag;
+mod upload_gc;
 mod user;
 
 pub use self::auth::AuthService;
 pub use self::category::CategoryService;
 pub use self::comment::CommentService;
+pub use self::comment_filter::CommentFilterService;
 pub use self::file::FileService;
+pub use self::media::MediaService;
 pub use self::posts::PostService;
+pub use self::scheduler::{spawn_publish_scheduler, PublishScheduler};
+pub use self::tag::TagService;
+pub use self::upload_gc::{spawn_upload_gc, UploadGc};
 pub use self::user::UserService;
@@ -1,20 +1,219 @@
+use std::collections::{BTreeMap, HashMap};
+
 use crate::{
-    abstract_trait::{DynPostsRepository, PostsServiceTrait},
+    abstract_trait::{
+        DynImageVariantRepository, DynPostsRepository, DynTagRepository, DynUserRepository,
+        PostsServiceTrait,
+    },
+    config::Claims,
     domain::{
-        ApiResponse, ApiResponsePagination, CreatePostRequest, ErrorResponse, FindAllPostRequest,
-        Pagination, PostRelationResponse, PostResponse, UpdatePostRequest,
+        ApiResponse, ApiResponsePagination, CreatePostRequest, FindAllPostRequest,
+        Pagination, PostFilter, PostRelationResponse, PostResponse, PostTransition,
+        PostRevisionResponse, PostSlugLookup, PostVisibility, RevisionDiffResponse,
+        SchedulePostRequest,
+        SearchMode, UpdatePostRequest,
     },
-    utils::AppError,
+    entities::{post_revisions, posts, sea_orm_active_enums::PostStatus},
+    service::TagService,
+    utils::{build_tsquery, line_diff, AppError, Permission},
 };
 use async_trait::async_trait;
+use chrono::Utc;
 
 pub struct PostService {
     repository: DynPostsRepository,
+    user_repository: DynUserRepository,
+    tag_repository: DynTagRepository,
+    image_variant_repository: DynImageVariantRepository,
+    media_base_url: String,
 }
 
 impl PostService {
-    pub fn new(repository: DynPostsRepository) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: DynPostsRepository,
+        user_repository: DynUserRepository,
+        tag_repository: DynTagRepository,
+        image_variant_repository: DynImageVariantRepository,
+        media_base_url: &str,
+    ) -> Self {
+        Self {
+            repository,
+            user_repository,
+            tag_repository,
+            image_variant_repository,
+            media_base_url: media_base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Turns a stored image key into the absolute URL of the `/media` route.
+    fn image_url(&self, img: &str) -> String {
+        if img.starts_with("http://") || img.starts_with("https://") {
+            return img.to_string();
+        }
+
+        format!("{}/{}", self.media_base_url, img)
+    }
+
+    async fn ensure_tags_exist(&self, tag_ids: &[i32]) -> Result<(), AppError> {
+        if tag_ids.is_empty() {
+            return Ok(());
+        }
+
+        let found = self
+            .tag_repository
+            .find_by_ids(tag_ids)
+            .await?;
+
+        if let Some(missing) = tag_ids
+            .iter()
+            .find(|id| !found.iter().any(|tag| tag.id == **id))
+        {
+            return Err(AppError::Validation(format!(
+                "Tag with id {} does not exist",
+                missing
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn with_tags(&self, posts: Vec<posts::Model>) -> Result<Vec<PostResponse>, AppError> {
+        let ids: Vec<i32> = posts.iter().map(|post| post.id).collect();
+
+        let mut tags_by_post: HashMap<i32, Vec<String>> = HashMap::new();
+        for (post_id, tag) in self
+            .tag_repository
+            .find_by_posts(&ids)
+            .await?
+        {
+            tags_by_post.entry(post_id).or_default().push(tag.name);
+        }
+
+        let image_keys: Vec<String> = posts.iter().map(|post| post.img.clone()).collect();
+
+        // Variants arrive narrowest first, so each srcset lists widths in order.
+        let mut srcsets: HashMap<String, BTreeMap<String, Vec<String>>> = HashMap::new();
+        for variant in self
+            .image_variant_repository
+            .find_by_image_keys(&image_keys)
+            .await?
+        {
+            srcsets
+                .entry(variant.image_key)
+                .or_default()
+                .entry(variant.content_type)
+                .or_default()
+                .push(format!("{} {}w", self.image_url(&variant.key), variant.width));
+        }
+
+        Ok(posts
+            .into_iter()
+            .map(|post| {
+                let tags = tags_by_post.remove(&post.id).unwrap_or_default();
+                let img = self.image_url(&post.img);
+                let srcset = srcsets
+                    .get(&post.img)
+                    .map(|by_type| {
+                        by_type
+                            .iter()
+                            .map(|(content_type, candidates)| {
+                                (content_type.clone(), candidates.join(", "))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                PostResponse {
+                    tags,
+                    img,
+                    srcset,
+                    ..PostResponse::from(post)
+                }
+            })
+            .collect())
+    }
+
+    async fn post_response(&self, post: posts::Model) -> Result<PostResponse, AppError> {
+        let mut responses = self.with_tags(vec![post]).await?;
+
+        Ok(responses.remove(0))
+    }
+
+    fn visibility(claims: Option<&Claims>) -> PostVisibility {
+        match claims {
+            Some(claims) if claims.role.has_permission(Permission::PublishPosts) => {
+                PostVisibility::Staff
+            }
+            Some(claims) => PostVisibility::Author(claims.user_id as i32),
+            None => PostVisibility::Public,
+        }
+    }
+
+    /// Whether the caller may see the post: published ones, their own drafts, or
+    /// anything at all with the publish permission.
+    pub(crate) fn can_view(claims: Option<&Claims>, post: &posts::Model) -> bool {
+        match Self::visibility(claims) {
+            PostVisibility::Staff => true,
+            PostVisibility::Author(user_id) => {
+                post.status == PostStatus::Published || post.user_id == user_id
+            }
+            PostVisibility::Public => post.status == PostStatus::Published,
+        }
+    }
+
+    async fn find_post(&self, post_id: i32) -> Result<posts::Model, AppError> {
+        self
+            .repository
+            .get_post(post_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "Posts with id {} not found",
+                    post_id
+                ))
+            })
+    }
+
+    async fn ensure_owner(&self, claims: &Claims, post_id: i32) -> Result<posts::Model, AppError> {
+        let post = self.find_post(post_id).await?;
+
+        if !claims.is_owner_or_admin(post.user_id) {
+            return Err(AppError::Forbidden(
+                "Only the author or an admin can modify this post".to_string(),
+            ));
+        }
+
+        Ok(post)
+    }
+
+    // Revisions can hold content that was never published, so only the author and reviewers see them.
+    async fn ensure_history_access(&self, claims: &Claims, post_id: i32) -> Result<(), AppError> {
+        let post = self.find_post(post_id).await?;
+
+        if !claims.is_owner_or_admin(post.user_id)
+            && !claims.role.has_permission(Permission::PublishPosts)
+        {
+            return Err(AppError::Forbidden(
+                "Only the author or a reviewer can view the history of this post".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn find_revision(
+        &self,
+        post_id: i32,
+        revision: i32,
+    ) -> Result<post_revisions::Model, AppError> {
+        self.repository
+            .get_revision(post_id, revision)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "Revision {} of post {} not found",
+                    revision, post_id
+                ))
+            })
     }
 }
 
@@ -22,8 +221,9 @@ impl PostService {
 impl PostsServiceTrait for PostService {
     async fn get_all_posts(
         &self,
+        claims: Option<&Claims>,
         req: FindAllPostRequest,
-    ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse> {
+    ) -> Result<ApiResponsePagination<Vec<PostResponse>>, AppError> {
         let page = req.page.max(1);
         let page_size = req.page_size.max(1);
         let search = if req.search.is_empty() {
@@ -32,14 +232,66 @@ impl PostsServiceTrait for PostService {
             Some(req.search.clone())
         };
 
-        let (posts, total_items) = self
-            .repository
-            .get_all_posts(page, page_size, search)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+        let mut tags: Vec<String> = req
+            .tags
+            .split(',')
+            .map(TagService::normalize_name)
+            .filter(|tag| !tag.is_empty())
+            .collect();
+        tags.sort();
+        tags.dedup();
 
-        let responses: Vec<PostResponse> = posts.into_iter().map(PostResponse::from).collect();
+        let filter = PostFilter {
+            search,
+            status: req.status,
+            tags,
+            tag_match: req.tag_match,
+            category_id: req.category_id,
+            visibility: Self::visibility(claims),
+        };
+
+        let tsquery = match req.search_mode {
+            SearchMode::FullText => build_tsquery(&req.search),
+            SearchMode::Basic => None,
+        };
+
+        let (responses, total_items) = if let Some(tsquery) = tsquery {
+            let (hits, total_items) = self
+                .repository
+                .search_posts(page, page_size, &filter, &tsquery)
+                .await?;
+
+            let (posts, matches): (Vec<_>, Vec<_>) = hits
+                .into_iter()
+                .map(|(post, rank, headline)| (post, (rank, headline)))
+                .unzip();
+
+            let responses = self
+                .with_tags(posts)
+                .await?
+                .into_iter()
+                .zip(matches)
+                .map(|(response, (rank, headline))| PostResponse {
+                    rank: Some(rank),
+                    headline: Some(headline),
+                    ..response
+                })
+                .collect();
+
+            (responses, total_items)
+        } else {
+            let filter = PostFilter {
+                search: filter.search.filter(|_| req.search_mode == SearchMode::Basic),
+                ..filter
+            };
+
+            let (posts, total_items) = self
+                .repository
+                .get_all_posts(page, page_size, &filter)
+                .await?;
+
+            (self.with_tags(posts).await?, total_items)
+        };
 
         let total_pages = (total_items as f64 / req.page_size as f64).ceil() as i32;
 
@@ -52,45 +304,93 @@ impl PostsServiceTrait for PostService {
                 page_size: req.page_size,
                 total_items,
                 total_pages,
+                next_cursor: None,
             },
         })
     }
 
     async fn get_post(
         &self,
+        claims: Option<&Claims>,
         post_id: i32,
-    ) -> Result<Option<ApiResponse<PostResponse>>, ErrorResponse> {
+    ) -> Result<Option<ApiResponse<PostResponse>>, AppError> {
         let post = self
             .repository
             .get_post(post_id)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .await?
+            .filter(|post| Self::can_view(claims, post));
 
         if let Some(post) = post {
             Ok(Some(ApiResponse {
                 status: "success".to_string(),
                 message: "Post retrieved successfully".to_string(),
-                data: PostResponse::from(post),
+                data: self.post_response(post).await?,
             }))
         } else {
-            Err(ErrorResponse::from(AppError::NotFound(format!(
+            Err(AppError::NotFound(format!(
                 "Posts with id {} not found",
                 post_id
-            ))))
+            )))
         }
     }
 
+    async fn get_post_by_slug(
+        &self,
+        claims: Option<&Claims>,
+        slug: &str,
+    ) -> Result<PostSlugLookup, AppError> {
+        let not_found =
+            || AppError::NotFound(format!("Post with slug {} not found", slug));
+
+        let post = self
+            .repository
+            .get_post_by_slug(slug)
+            .await?;
+
+        if let Some(post) = post {
+            if !Self::can_view(claims, &post) {
+                return Err(not_found());
+            }
+
+            return Ok(PostSlugLookup::Found(Box::new(ApiResponse {
+                status: "success".to_string(),
+                message: "Post retrieved successfully".to_string(),
+                data: self.post_response(post).await?,
+            })));
+        }
+
+        let moved = self
+            .repository
+            .get_post_by_previous_slug(slug)
+            .await?
+            .filter(|post| Self::can_view(claims, post))
+            .ok_or_else(not_found)?;
+
+        Ok(PostSlugLookup::Moved(moved.slug))
+    }
+
     async fn get_post_relation(
         &self,
+        claims: Option<&Claims>,
         post_id: i32,
-    ) -> Result<ApiResponse<PostRelationResponse>, ErrorResponse> {
+    ) -> Result<ApiResponse<PostRelationResponse>, AppError> {
+        let visible = self
+            .repository
+            .get_post(post_id)
+            .await?
+            .is_some_and(|post| Self::can_view(claims, &post));
+
+        if !visible {
+            return Err(AppError::NotFound(format!(
+                "Posts with id {} not found",
+                post_id
+            )));
+        }
+
         let relations = self
             .repository
             .get_post_relation(post_id)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .await?;
 
         let first_relation = relations
             .into_iter()
@@ -106,46 +406,238 @@ impl PostsServiceTrait for PostService {
 
     async fn create_post(
         &self,
+        claims: &Claims,
         input: &CreatePostRequest,
-    ) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
+    ) -> Result<ApiResponse<PostResponse>, AppError> {
+        let author = self
+            .user_repository
+            .find_by_id(claims.user_id as i32)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        let user_name = format!("{} {}", author.firstname, author.lastname);
+
+        self.ensure_tags_exist(&input.tag_ids).await?;
+
         let post = self
             .repository
-            .create_post(input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .create_post(input, author.id, &user_name)
+            .await?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Post created successfully".to_string(),
-            data: PostResponse::from(post),
+            data: self.post_response(post).await?,
         })
     }
 
     async fn update_post(
         &self,
+        claims: &Claims,
         input: &UpdatePostRequest,
-    ) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
+    ) -> Result<ApiResponse<PostResponse>, AppError> {
+        if let Some(post_id) = input.post_id {
+            self.ensure_owner(claims, post_id).await?;
+        }
+
+        if let Some(tag_ids) = &input.tag_ids {
+            self.ensure_tags_exist(tag_ids).await?;
+        }
+
         let post = self
             .repository
-            .update_post(input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .update_post(input, claims.user_id as i32)
+            .await?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Post updated successfully".to_string(),
-            data: PostResponse::from(post),
+            data: self.post_response(post).await?,
+        })
+    }
+
+    async fn transition_post(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        transition: PostTransition,
+    ) -> Result<ApiResponse<PostResponse>, AppError> {
+        let post = self.find_post(post_id).await?;
+
+        let is_reviewer = claims.role.has_permission(Permission::PublishPosts);
+        let is_allowed = match transition {
+            PostTransition::Submit | PostTransition::Restore => {
+                claims.is_owner_or_admin(post.user_id)
+            }
+            PostTransition::Publish | PostTransition::Reject => is_reviewer,
+            PostTransition::Archive => is_reviewer || claims.is_owner_or_admin(post.user_id),
+        };
+
+        if !is_allowed {
+            return Err(AppError::Forbidden(
+                "You are not allowed to change the status of this post".to_string(),
+            ));
+        }
+
+        let target = transition.target();
+        let published_at =
+            (target == PostStatus::Published).then(|| Utc::now().fixed_offset());
+
+        let post = self
+            .repository
+            .update_status(post_id, transition.allowed_from(), target, published_at)
+            .await?
+            .ok_or_else(|| {
+                AppError::InvalidStatusTransition(format!(
+                    "Cannot move post {} from {:?} to {:?}",
+                    post_id, post.status, target
+                ))
+            })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post status updated successfully".to_string(),
+            data: self.post_response(post).await?,
         })
     }
 
-    async fn delete_post(&self, post_id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
+    async fn schedule_post(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        input: &SchedulePostRequest,
+    ) -> Result<ApiResponse<PostResponse>, AppError> {
+        if !claims.role.has_permission(Permission::PublishPosts) {
+            return Err(AppError::Forbidden(
+                "You are not allowed to schedule posts".to_string(),
+            ));
+        }
+
+        if input.publish_at <= Utc::now() {
+            return Err(AppError::Validation(
+                "publish_at must be in the future".to_string(),
+            ));
+        }
+
+        let post = self.find_post(post_id).await?;
+
+        let from = [PostStatus::Draft, PostStatus::InReview, PostStatus::Scheduled];
+
+        let post = self
+            .repository
+            .schedule_post(post_id, &from, input.publish_at)
+            .await?
+            .ok_or_else(|| {
+                AppError::InvalidStatusTransition(format!(
+                    "Cannot schedule post {} from {:?}",
+                    post_id, post.status
+                ))
+            })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post scheduled successfully".to_string(),
+            data: self.post_response(post).await?,
+        })
+    }
+
+    async fn get_post_revisions(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+    ) -> Result<ApiResponse<Vec<PostRevisionResponse>>, AppError> {
+        self.ensure_history_access(claims, post_id).await?;
+
+        let revisions = self
+            .repository
+            .get_revisions(post_id)
+            .await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post revisions retrieved successfully".to_string(),
+            data: revisions.into_iter().map(PostRevisionResponse::from).collect(),
+        })
+    }
+
+    async fn get_post_revision(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        revision: i32,
+    ) -> Result<ApiResponse<PostRevisionResponse>, AppError> {
+        self.ensure_history_access(claims, post_id).await?;
+
+        let revision = self.find_revision(post_id, revision).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post revision retrieved successfully".to_string(),
+            data: PostRevisionResponse::from(revision),
+        })
+    }
+
+    async fn diff_post_revisions(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        from: i32,
+        to: i32,
+    ) -> Result<ApiResponse<RevisionDiffResponse>, AppError> {
+        self.ensure_history_access(claims, post_id).await?;
+
+        let old = self.find_revision(post_id, from).await?;
+        let new = self.find_revision(post_id, to).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post revision diff generated successfully".to_string(),
+            data: RevisionDiffResponse {
+                post_id,
+                from,
+                to,
+                title_changed: old.title != new.title,
+                lines: line_diff(&old.body, &new.body),
+            },
+        })
+    }
+
+    async fn restore_post_revision(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+        revision: i32,
+    ) -> Result<ApiResponse<PostResponse>, AppError> {
+        self.ensure_owner(claims, post_id).await?;
+
+        let post = self
+            .repository
+            .restore_revision(post_id, revision, claims.user_id as i32)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "Revision {} of post {} not found",
+                    revision, post_id
+                ))
+            })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post revision restored successfully".to_string(),
+            data: self.post_response(post).await?,
+        })
+    }
+
+    async fn delete_post(
+        &self,
+        claims: &Claims,
+        post_id: i32,
+    ) -> Result<ApiResponse<()>, AppError> {
+        self.ensure_owner(claims, post_id).await?;
+
         self.repository
             .delete_post(post_id)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .await?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
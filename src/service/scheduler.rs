@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::{sync::RwLock, task::JoinHandle};
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::{DynPostsRepository, DynPublishScheduler, PublishSchedulerTrait},
+    domain::{ApiResponse, SchedulerStatusResponse},
+    utils::AppError,
+};
+
+// Upper bound on posts published per tick so one run never holds row locks for long.
+const BATCH_SIZE: u64 = 100;
+
+pub struct PublishScheduler {
+    repository: DynPostsRepository,
+    interval: Duration,
+    status: RwLock<SchedulerStatusResponse>,
+}
+
+impl PublishScheduler {
+    pub fn new(repository: DynPostsRepository, interval: Duration) -> Self {
+        Self {
+            repository,
+            interval,
+            status: RwLock::new(SchedulerStatusResponse {
+                interval_seconds: interval.as_secs(),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl PublishSchedulerTrait for PublishScheduler {
+    fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    async fn run_once(&self) -> Result<u64, AppError> {
+        let started_at = Utc::now().fixed_offset();
+        let mut published = 0;
+
+        let result = loop {
+            match self.repository.publish_due_posts(started_at, BATCH_SIZE).await {
+                Ok(posts) => {
+                    published += posts.len() as u64;
+
+                    if (posts.len() as u64) < BATCH_SIZE {
+                        break Ok(published);
+                    }
+                }
+                Err(err) => break Err(AppError::from(err)),
+            }
+        };
+
+        let mut status = self.status.write().await;
+        status.last_run_at = Some(started_at);
+        status.last_published = published;
+        status.total_published += published;
+
+        match &result {
+            Ok(_) => {
+                status.last_success_at = Some(started_at);
+                status.last_error = None;
+            }
+            Err(err) => status.last_error = Some(err.to_string()),
+        }
+
+        result
+    }
+
+    async fn status(&self) -> ApiResponse<SchedulerStatusResponse> {
+        ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduler status retrieved successfully".to_string(),
+            data: self.status.read().await.clone(),
+        }
+    }
+}
+
+pub fn spawn_publish_scheduler(scheduler: DynPublishScheduler) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(scheduler.interval());
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        info!("Publish scheduler started, interval {:?}", scheduler.interval());
+
+        loop {
+            ticker.tick().await;
+
+            match scheduler.run_once().await {
+                Ok(0) => {}
+                Ok(count) => info!("Publish scheduler promoted {} post(s)", count),
+                Err(err) => error!("Publish scheduler run failed: {}", err),
+            }
+        }
+    })
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use crate::{
+    abstract_trait::{DynTagRepository, TagServiceTrait},
+    domain::{
+        ApiResponse, ApiResponsePagination, CreateTagRequest, FindAllTagRequest, Pagination,
+        TagResponse, UpdateTagRequest,
+    },
+    entities::tags,
+    utils::AppError,
+};
+use async_trait::async_trait;
+
+pub struct TagService {
+    repository: DynTagRepository,
+}
+
+impl TagService {
+    pub fn new(repository: DynTagRepository) -> Self {
+        Self { repository }
+    }
+
+    // Tags are matched by name in post filters, so store them in one canonical form.
+    pub fn normalize_name(name: &str) -> String {
+        name.trim().to_lowercase()
+    }
+
+    async fn validate_name(&self, name: &str, current_id: Option<i32>) -> Result<(), AppError> {
+        if name.is_empty() {
+            return Err(AppError::Validation(
+                "Tag name must not be empty".to_string(),
+            ));
+        }
+
+        let existing = self.repository.find_by_name(name).await?;
+
+        if existing.is_some_and(|tag| Some(tag.id) != current_id) {
+            return Err(AppError::Conflict(format!("Tag '{}' already exists", name)));
+        }
+
+        Ok(())
+    }
+
+    async fn with_counts(&self, tags: Vec<tags::Model>) -> Result<Vec<TagResponse>, AppError> {
+        let ids: Vec<i32> = tags.iter().map(|tag| tag.id).collect();
+
+        let counts: HashMap<i32, i64> = self
+            .repository
+            .count_published_posts(&ids)
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(tags
+            .into_iter()
+            .map(|tag| {
+                let count = counts.get(&tag.id).copied().unwrap_or(0);
+                TagResponse::new(tag, count)
+            })
+            .collect())
+    }
+
+    async fn with_count(&self, tag: tags::Model) -> Result<TagResponse, AppError> {
+        let mut responses = self.with_counts(vec![tag]).await?;
+
+        Ok(responses.remove(0))
+    }
+}
+
+#[async_trait]
+impl TagServiceTrait for TagService {
+    async fn get_tags(
+        &self,
+        req: FindAllTagRequest,
+    ) -> Result<ApiResponsePagination<Vec<TagResponse>>, AppError> {
+        let page = req.page.max(1);
+        let page_size = req.page_size.max(1);
+        let search = if req.search.is_empty() {
+            None
+        } else {
+            Some(Self::normalize_name(&req.search))
+        };
+
+        let (tags, total_items) = self.repository.find_all(page, page_size, search).await?;
+
+        let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+        Ok(ApiResponsePagination {
+            status: "success".to_string(),
+            message: "Tags retrieved successfully".to_string(),
+            data: self.with_counts(tags).await?,
+            pagination: Pagination {
+                page,
+                page_size,
+                total_items,
+                total_pages,
+                next_cursor: None,
+            },
+        })
+    }
+
+    async fn get_tag(&self, id: i32) -> Result<ApiResponse<TagResponse>, AppError> {
+        let tag = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Tag with id {} not found", id)))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Tag retrieved successfully".to_string(),
+            data: self.with_count(tag).await?,
+        })
+    }
+
+    async fn create_tag(
+        &self,
+        input: &CreateTagRequest,
+    ) -> Result<ApiResponse<TagResponse>, AppError> {
+        let name = Self::normalize_name(&input.name);
+        self.validate_name(&name, None).await?;
+
+        let tag = self.repository.create(&name).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Tag created successfully".to_string(),
+            data: TagResponse::new(tag, 0),
+        })
+    }
+
+    async fn update_tag(
+        &self,
+        input: &UpdateTagRequest,
+    ) -> Result<ApiResponse<TagResponse>, AppError> {
+        let id = input
+            .id
+            .ok_or_else(|| AppError::BadRequest("Tag ID is required".to_string()))?;
+
+        let name = Self::normalize_name(&input.name);
+        self.validate_name(&name, Some(id)).await?;
+
+        let tag = self
+            .repository
+            .update(id, &name)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Tag with id {} not found", id)))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Tag updated successfully".to_string(),
+            data: self.with_count(tag).await?,
+        })
+    }
+
+    async fn delete_tag(&self, id: i32) -> Result<ApiResponse<()>, AppError> {
+        self.repository.delete(id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Tag deleted successfully".to_string(),
+            data: (),
+        })
+    }
+}
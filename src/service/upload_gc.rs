@@ -0,0 +1,254 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use tokio::{
+    sync::{Mutex, RwLock},
+    task::JoinHandle,
+};
+use tracing::{error, info, warn};
+
+use crate::{
+    abstract_trait::{
+        DynImageVariantRepository, DynMediaRepository, DynStorageBackend, DynUploadGc,
+        UploadGcTrait,
+    },
+    config::UploadGcConfig,
+    domain::{ApiResponse, OrphanedFileResponse, UploadGcReportResponse, UploadGcStatusResponse},
+    utils::AppError,
+};
+
+pub struct UploadGc {
+    storage: DynStorageBackend,
+    media_repository: DynMediaRepository,
+    variant_repository: DynImageVariantRepository,
+    interval: Duration,
+    grace_period: Duration,
+    status: RwLock<UploadGcStatusResponse>,
+    // Keeps an admin-triggered run from overlapping the background sweep.
+    running: Mutex<()>,
+}
+
+impl UploadGc {
+    pub fn new(
+        storage: DynStorageBackend,
+        media_repository: DynMediaRepository,
+        variant_repository: DynImageVariantRepository,
+        config: &UploadGcConfig,
+    ) -> Self {
+        Self {
+            storage,
+            media_repository,
+            variant_repository,
+            interval: config.interval,
+            grace_period: config.grace_period,
+            status: RwLock::new(UploadGcStatusResponse {
+                interval_seconds: config.interval.as_secs(),
+                grace_period_seconds: config.grace_period.as_secs(),
+                ..Default::default()
+            }),
+            running: Mutex::new(()),
+        }
+    }
+
+    async fn sweep(
+        &self,
+        dry_run: bool,
+        started_at: DateTime<Utc>,
+    ) -> Result<UploadGcReportResponse, AppError> {
+        let cutoff = started_at - TimeDelta::from_std(self.grace_period).unwrap_or(TimeDelta::MAX);
+        let mut failures = Vec::new();
+
+        // Listed first: anything uploaded after this point is not looked at.
+        let objects = self.storage.list().await?;
+
+        // Media rows go before their files. The foreign keys from posts and
+        // revisions refuse the delete if an upload was deduplicated onto the
+        // entry since it was found, which keeps its files as well.
+        let mut orphaned_media = HashMap::new();
+        for media in self
+            .media_repository
+            .find_unreferenced(cutoff.fixed_offset())
+            .await?
+        {
+            if !dry_run {
+                if let Err(e) = self.media_repository.delete(media.id).await {
+                    failures.push(format!("media {}: {}", media.id, e));
+                    continue;
+                }
+            }
+            orphaned_media.insert(media.path, media.id);
+        }
+
+        let mut referenced: HashSet<String> = self
+            .media_repository
+            .find_image_references()
+            .await?
+            .into_iter()
+            .collect();
+        referenced.extend(
+            self.media_repository
+                .find_paths()
+                .await?
+                .into_iter()
+                .filter(|path| !orphaned_media.contains_key(path)),
+        );
+
+        let images: Vec<String> = referenced.iter().cloned().collect();
+        referenced.extend(
+            self.variant_repository
+                .find_by_image_keys(&images)
+                .await?
+                .into_iter()
+                .map(|variant| variant.key),
+        );
+
+        let scanned_files = objects.len() as u64;
+        let referenced_files = objects
+            .iter()
+            .filter(|object| referenced.contains(&object.key))
+            .count() as u64;
+
+        let orphaned: Vec<_> = objects
+            .into_iter()
+            .filter(|object| !referenced.contains(&object.key) && object.last_modified < cutoff)
+            .collect();
+
+        let mut deleted_files = 0;
+        let mut reclaimed_bytes = 0;
+        if !dry_run {
+            let mut image_keys: BTreeSet<&str> =
+                orphaned_media.keys().map(String::as_str).collect();
+
+            for object in &orphaned {
+                match self.storage.delete(&object.key).await {
+                    Ok(()) => {
+                        deleted_files += 1;
+                        reclaimed_bytes += object.size;
+                        image_keys.insert(&object.key);
+                    }
+                    Err(e) => failures.push(format!("{}: {}", object.key, e)),
+                }
+            }
+
+            for image_key in image_keys {
+                if let Err(e) = self.variant_repository.delete_by_image_key(image_key).await {
+                    failures.push(format!("variants of {}: {}", image_key, e));
+                }
+            }
+        }
+
+        let mut orphaned_media_ids: Vec<i32> = orphaned_media.values().copied().collect();
+        orphaned_media_ids.sort_unstable();
+
+        Ok(UploadGcReportResponse {
+            dry_run,
+            grace_period_seconds: self.grace_period.as_secs(),
+            scanned_files,
+            referenced_files,
+            orphaned_bytes: orphaned.iter().map(|object| object.size).sum(),
+            orphaned_files: orphaned
+                .into_iter()
+                .map(|object| OrphanedFileResponse {
+                    media_id: orphaned_media.get(&object.key).copied(),
+                    key: object.key,
+                    size: object.size,
+                    last_modified: object.last_modified.fixed_offset(),
+                })
+                .collect(),
+            orphaned_media_ids,
+            deleted_files,
+            reclaimed_bytes,
+            failures,
+        })
+    }
+}
+
+#[async_trait]
+impl UploadGcTrait for UploadGc {
+    fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    async fn run(&self, dry_run: bool) -> Result<ApiResponse<UploadGcReportResponse>, AppError> {
+        let _running = self.running.lock().await;
+
+        let started_at = Utc::now();
+        let result = self.sweep(dry_run, started_at).await;
+
+        let mut status = self.status.write().await;
+        status.last_run_at = Some(started_at.fixed_offset());
+        status.last_dry_run = dry_run;
+
+        match &result {
+            Ok(report) => {
+                status.last_success_at = Some(started_at.fixed_offset());
+                status.last_deleted_files = report.deleted_files;
+                status.last_reclaimed_bytes = report.reclaimed_bytes;
+                status.total_deleted_files += report.deleted_files;
+                status.total_reclaimed_bytes += report.reclaimed_bytes;
+                status.last_error = None;
+            }
+            Err(err) => status.last_error = Some(err.to_string()),
+        }
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: if dry_run {
+                "Orphaned uploads found".to_string()
+            } else {
+                "Orphaned uploads removed".to_string()
+            },
+            data: result?,
+        })
+    }
+
+    async fn status(&self) -> ApiResponse<UploadGcStatusResponse> {
+        ApiResponse {
+            status: "success".to_string(),
+            message: "Upload garbage collector status retrieved successfully".to_string(),
+            data: self.status.read().await.clone(),
+        }
+    }
+}
+
+/// Starts the background sweeper, unless its interval is zero.
+pub fn spawn_upload_gc(gc: DynUploadGc) -> Option<JoinHandle<()>> {
+    if gc.interval().is_zero() {
+        info!("Upload garbage collector disabled");
+        return None;
+    }
+
+    Some(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(gc.interval());
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        info!(
+            "Upload garbage collector started, interval {:?}",
+            gc.interval()
+        );
+
+        loop {
+            ticker.tick().await;
+
+            match gc.run(false).await {
+                Ok(response) => {
+                    let report = response.data;
+                    if report.deleted_files > 0 {
+                        info!(
+                            "Upload garbage collector removed {} file(s), {} byte(s)",
+                            report.deleted_files, report.reclaimed_bytes
+                        );
+                    }
+                    for failure in &report.failures {
+                        warn!("Upload garbage collector could not remove {}", failure);
+                    }
+                }
+                Err(err) => error!("Upload garbage collector run failed: {}", err),
+            }
+        }
+    }))
+}
@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::{DynUserRepository, UserServiceTrait},
-    domain::{ApiResponse, CreateUserRequest, ErrorResponse, UpdateUserRequest, UserResponse},
+    domain::{ApiResponse, CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest, UserResponse},
     utils::AppError,
 };
 use async_trait::async_trait;
@@ -20,17 +20,15 @@ impl UserServiceTrait for UserService {
     async fn create_user(
         &self,
         input: &CreateUserRequest,
-    ) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
-        let exists = self.repository.find_by_email_exists(&input.email).await
-            .map_err(AppError::from)  
-            .map_err(ErrorResponse::from)?; 
+    ) -> Result<ApiResponse<UserResponse>, AppError> {
+        let exists = self.repository.find_by_email_exists(&input.email).await?; 
 
         if exists {
-            return Err(ErrorResponse::from(AppError::EmailAlreadyExists));
+            return Err(AppError::EmailAlreadyExists);
         }
 
 
-        let user = self.repository.create_user(input).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+        let user = self.repository.create_user(input).await?;
         
         Ok(ApiResponse {
             status: "success".to_string(),
@@ -39,8 +37,8 @@ impl UserServiceTrait for UserService {
         })
     }
 
-    async fn find_by_email_exists(&self, email: &str) -> Result<ApiResponse<bool>, ErrorResponse> {
-        let exists = self.repository.find_by_email_exists(email).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn find_by_email_exists(&self, email: &str) -> Result<ApiResponse<bool>, AppError> {
+        let exists = self.repository.find_by_email_exists(email).await?;
         
         Ok(ApiResponse {
             status: "success".to_string(),
@@ -53,8 +51,8 @@ impl UserServiceTrait for UserService {
     async fn find_user_by_email(
         &self,
         email: &str,
-    ) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse> {
-        let user = self.repository.find_by_email(email).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    ) -> Result<Option<ApiResponse<UserResponse>>, AppError> {
+        let user = self.repository.find_by_email(email).await?;
         
         if let Some(user) = user {
             Ok(Some(ApiResponse {
@@ -63,15 +61,15 @@ impl UserServiceTrait for UserService {
                 data: UserResponse::from(user),
             }))
         } else {
-            Err(ErrorResponse::from(AppError::NotFound(format!("User with email {} not found", email))))
+            Err(AppError::NotFound(format!("User with email {} not found", email)))
         }
     }
 
     async fn find_by_id(
         &self,
         id: i32,
-    ) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse> {
-        let user = self.repository.find_by_id(id).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    ) -> Result<Option<ApiResponse<UserResponse>>, AppError> {
+        let user = self.repository.find_by_id(id).await?;
         
         if let Some(user) = user {
             Ok(Some(ApiResponse {
@@ -80,15 +78,15 @@ impl UserServiceTrait for UserService {
                 data: UserResponse::from(user),
             }))
         } else {
-            Err(ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id))))
+            Err(AppError::NotFound(format!("User with id {} not found", id)))
         }
     }
 
     async fn update_user(
         &self,
         input: &UpdateUserRequest,
-    ) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse> {
-        let user = self.repository.update_user(input).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    ) -> Result<Option<ApiResponse<UserResponse>>, AppError> {
+        let user = self.repository.update_user(input).await?;
         
         Ok(Some(ApiResponse {
             status: "success".to_string(),
@@ -97,8 +95,21 @@ impl UserServiceTrait for UserService {
         }))
     }
 
-    async fn delete_user(&self, email: &str) -> Result<ApiResponse<()>, ErrorResponse> {
-        self.repository.delete_user(email).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn update_role(
+        &self,
+        input: &UpdateUserRoleRequest,
+    ) -> Result<ApiResponse<UserResponse>, AppError> {
+        let user = self.repository.update_role(input).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "User role updated successfully".to_string(),
+            data: UserResponse::from(user),
+        })
+    }
+
+    async fn delete_user(&self, email: &str) -> Result<ApiResponse<()>, AppError> {
+        self.repository.delete_user(email).await?;
         
         Ok(ApiResponse {
             status: "success".to_string(),
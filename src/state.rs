@@ -1,6 +1,6 @@
 use sea_orm::DatabaseConnection;
 
-use crate::{config::{Hashing, JwtConfig}, utils::DependenciesInject};
+use crate::{abstract_trait::DynStorageBackend, config::{Config, Hashing, JwtConfig}, utils::DependenciesInject};
 
 #[derive(Clone)]
 pub struct AppState {
@@ -9,11 +9,21 @@ pub struct AppState {
 }
 
 impl AppState {
-    pub fn new(pool: DatabaseConnection, jwt_secret: &str) -> Self {
-        let jwt_config = JwtConfig::new(jwt_secret);
+    pub fn new(
+        pool: DatabaseConnection,
+        config: &Config,
+        storage: DynStorageBackend,
+    ) -> Self {
+        let jwt_config = JwtConfig::new(&config.jwt_secret);
         let hashing = Hashing::new();
 
-        let di_container = DependenciesInject::new(pool, hashing, jwt_config.clone());
+        let di_container = DependenciesInject::new(
+            pool,
+            hashing,
+            jwt_config.clone(),
+            storage,
+            config,
+        );
         
         Self { di_container, jwt_config }
     }
@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::{
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+};
+use tokio::fs;
+
+use crate::{
+    abstract_trait::{StorageBackend, StoredObject},
+    utils::AppError,
+};
+
+/// Stores objects as plain files under `root` and links to them through the
+/// `/media` route mounted at `public_url`.
+pub struct LocalStorage {
+    root: PathBuf,
+    public_url: String,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>, public_url: &str) -> Self {
+        Self {
+            root: root.into(),
+            public_url: public_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Resolves `key` below the root, refusing anything that could escape it.
+    fn resolve(&self, key: &str) -> Result<PathBuf, AppError> {
+        let relative = Path::new(key);
+        let is_plain = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+
+        if key.is_empty() || !is_plain {
+            return Err(AppError::BadRequest(format!(
+                "Invalid storage key '{}'",
+                key
+            )));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl StorageBackend for LocalStorage {
+    async fn put(&self, key: &str, source: &Path, _content_type: &str) -> Result<(), AppError> {
+        let target = self.resolve(key)?;
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).await.map_err(|e| {
+                AppError::StorageError(format!("Failed to create upload directory: {}", e))
+            })?;
+        }
+
+        // A rename is free on the same filesystem; the temp dir may live elsewhere.
+        if fs::rename(source, &target).await.is_err() {
+            fs::copy(source, &target)
+                .await
+                .map_err(|e| AppError::StorageError(format!("Failed to save file: {}", e)))?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        fs::remove_file(self.resolve(key)?)
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to delete file: {}", e)))
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, AppError> {
+        fs::try_exists(self.resolve(key)?)
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to stat file: {}", e)))
+    }
+
+    async fn url(&self, key: &str) -> Result<String, AppError> {
+        self.resolve(key)?;
+
+        Ok(format!("{}/{}", self.public_url, key))
+    }
+
+    async fn list(&self) -> Result<Vec<StoredObject>, AppError> {
+        let list_error =
+            |e: std::io::Error| AppError::StorageError(format!("Failed to list files: {}", e));
+
+        let mut objects = Vec::new();
+        let mut pending = vec![self.root.clone()];
+
+        while let Some(dir) = pending.pop() {
+            let mut entries = match fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                // Nothing has been uploaded yet.
+                Err(e) if e.kind() == ErrorKind::NotFound && dir == self.root => break,
+                Err(e) => return Err(list_error(e)),
+            };
+
+            while let Some(entry) = entries.next_entry().await.map_err(list_error)? {
+                let path = entry.path();
+                let metadata = entry.metadata().await.map_err(list_error)?;
+
+                if metadata.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+
+                let Ok(relative) = path.strip_prefix(&self.root) else {
+                    continue;
+                };
+                let key = relative
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+
+                objects.push(StoredObject {
+                    key,
+                    size: metadata.len(),
+                    last_modified: metadata
+                        .modified()
+                        .map(DateTime::<Utc>::from)
+                        .map_err(list_error)?,
+                });
+            }
+        }
+
+        Ok(objects)
+    }
+
+    fn local_path(&self, key: &str) -> Result<Option<PathBuf>, AppError> {
+        self.resolve(key).map(Some)
+    }
+}
@@ -0,0 +1,5 @@
+mod local;
+mod s3;
+
+pub use self::local::LocalStorage;
+pub use self::s3::{S3Settings, S3Storage};
@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use axum::http::Method;
+use futures::TryStreamExt;
+use object_store::{
+    aws::{AmazonS3, AmazonS3Builder},
+    path::Path as ObjectPath,
+    signer::Signer,
+    Attribute, Attributes, ObjectStore, PutMultipartOpts, WriteMultipart,
+};
+use std::{path::Path, time::Duration};
+use tokio::{fs::File, io::AsyncReadExt};
+
+use crate::{
+    abstract_trait::{StorageBackend, StoredObject},
+    utils::AppError,
+};
+
+/// Size of each uploaded part; S3 rejects smaller parts except for the last one.
+const PART_SIZE: usize = 5 * 1024 * 1024;
+/// Parts uploaded at once, which bounds the memory a single upload holds.
+const MAX_PARTS_IN_FLIGHT: usize = 4;
+
+#[derive(Debug, Clone)]
+pub struct S3Settings {
+    pub bucket: String,
+    pub region: String,
+    /// Custom endpoint for S3-compatible servers such as MinIO.
+    pub endpoint: Option<String>,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub url_ttl: Duration,
+    /// Prefix every key is stored under, so a shared bucket's other objects are left alone.
+    pub key_prefix: String,
+}
+
+/// Stores objects in an S3 bucket and hands out presigned download URLs.
+pub struct S3Storage {
+    store: AmazonS3,
+    url_ttl: Duration,
+    prefix: Option<ObjectPath>,
+}
+
+impl S3Storage {
+    pub fn new(settings: &S3Settings) -> Result<Self, AppError> {
+        let mut builder = AmazonS3Builder::new()
+            .with_bucket_name(&settings.bucket)
+            .with_region(&settings.region)
+            .with_access_key_id(&settings.access_key_id)
+            .with_secret_access_key(&settings.secret_access_key);
+
+        if let Some(endpoint) = &settings.endpoint {
+            // Local stand-ins usually run over plain HTTP with path-style buckets.
+            builder = builder
+                .with_endpoint(endpoint)
+                .with_allow_http(endpoint.starts_with("http://"))
+                .with_virtual_hosted_style_request(false);
+        }
+
+        let store = builder
+            .build()
+            .map_err(|e| AppError::StorageError(format!("Invalid S3 configuration: {}", e)))?;
+
+        let prefix = match settings.key_prefix.trim_matches('/') {
+            "" => None,
+            prefix => Some(ObjectPath::parse(prefix).map_err(|_| {
+                AppError::StorageError(format!("Invalid S3 key prefix '{}'", prefix))
+            })?),
+        };
+
+        Ok(Self {
+            store,
+            url_ttl: settings.url_ttl,
+            prefix,
+        })
+    }
+
+    fn location(&self, key: &str) -> Result<ObjectPath, AppError> {
+        let path = ObjectPath::parse(key)
+            .map_err(|_| AppError::BadRequest(format!("Invalid storage key '{}'", key)))?;
+
+        Ok(match &self.prefix {
+            Some(prefix) => prefix.parts().chain(path.parts()).collect(),
+            None => path,
+        })
+    }
+
+    /// The key of an object listed below the prefix.
+    fn key(&self, location: &ObjectPath) -> String {
+        match self
+            .prefix
+            .as_ref()
+            .and_then(|prefix| location.prefix_match(prefix))
+        {
+            Some(parts) => parts.collect::<ObjectPath>().to_string(),
+            None => location.to_string(),
+        }
+    }
+
+    /// Feeds the file to the upload one part at a time.
+    async fn copy_parts(file: &mut File, writer: &mut WriteMultipart) -> Result<(), AppError> {
+        let mut buffer = vec![0; PART_SIZE];
+
+        loop {
+            writer
+                .wait_for_capacity(MAX_PARTS_IN_FLIGHT)
+                .await
+                .map_err(|e| AppError::StorageError(format!("Failed to save file: {}", e)))?;
+
+            let read = file
+                .read(&mut buffer)
+                .await
+                .map_err(|e| AppError::StorageError(format!("Failed to read upload: {}", e)))?;
+            if read == 0 {
+                return Ok(());
+            }
+
+            writer.write(&buffer[..read]);
+        }
+    }
+}
+
+#[async_trait]
+impl StorageBackend for S3Storage {
+    async fn put(&self, key: &str, source: &Path, content_type: &str) -> Result<(), AppError> {
+        let location = self.location(key)?;
+        let mut file = File::open(source)
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to read upload: {}", e)))?;
+
+        let mut attributes = Attributes::new();
+        attributes.insert(Attribute::ContentType, content_type.to_string().into());
+
+        let options = PutMultipartOpts {
+            attributes,
+            ..Default::default()
+        };
+
+        // Stream the spooled file in parts instead of reading it into memory whole
+        let upload = self
+            .store
+            .put_multipart_opts(&location, options)
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to save file: {}", e)))?;
+        let mut writer = WriteMultipart::new_with_chunk_size(upload, PART_SIZE);
+
+        if let Err(err) = Self::copy_parts(&mut file, &mut writer).await {
+            // Abort so the bucket does not keep the parts uploaded so far
+            let _ = writer.abort().await;
+            return Err(err);
+        }
+
+        writer
+            .finish()
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to save file: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        self.store
+            .delete(&self.location(key)?)
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to delete file: {}", e)))
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, AppError> {
+        match self.store.head(&self.location(key)?).await {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::NotFound { .. }) => Ok(false),
+            Err(e) => Err(AppError::StorageError(format!(
+                "Failed to stat file: {}",
+                e
+            ))),
+        }
+    }
+
+    async fn url(&self, key: &str) -> Result<String, AppError> {
+        let url = self
+            .store
+            .signed_url(Method::GET, &self.location(key)?, self.url_ttl)
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to presign URL: {}", e)))?;
+
+        Ok(url.to_string())
+    }
+
+    async fn list(&self) -> Result<Vec<StoredObject>, AppError> {
+        // Only objects below the prefix are ours to report, and so to collect.
+        self.store
+            .list(self.prefix.as_ref())
+            .map_ok(|meta| StoredObject {
+                key: self.key(&meta.location),
+                size: meta.size as u64,
+                last_modified: meta.last_modified,
+            })
+            .try_collect()
+            .await
+            .map_err(|e| AppError::StorageError(format!("Failed to list files: {}", e)))
+    }
+}
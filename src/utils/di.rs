@@ -1,32 +1,53 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use sea_orm::DatabaseConnection;
 
 use crate::{
     abstract_trait::{
-        DynAuthService, DynCategoryRepository, DynCategoryService, DynCommentRepository,
-        DynCommentService, DynFileService, DynPostsRepository, DynPostsService, DynUserRepository,
-        DynUserService,
+        DynAuthService, DynCategoryRepository, DynCategoryService, DynCommentFilter,
+        DynCommentFilterRepository, DynCommentFilterService, DynCommentRepository,
+        DynCommentService, DynFileService, DynImageVariantRepository, DynMediaRepository,
+        DynMediaService, DynPostsRepository, DynPostsService, DynPublishScheduler,
+        DynStorageBackend, DynTagRepository, DynTagService, DynTokenRepository, DynUploadGc,
+        DynUserRepository, DynUserService,
+    },
+    comment_filter::{BlocklistFilter, DuplicateFilter, HoneypotFilter, LinkLimitFilter},
+    config::{Config, Hashing, JwtConfig},
+    repository::{
+        CategoryRepository, CommentFilterRepository, CommentRepository, ImageVariantRepository,
+        MediaRepository, PostRepository, TagRepository, TokenRepository, UserRepository,
     },
-    config::{Hashing, JwtConfig},
-    repository::{CategoryRepository, CommentRepository, PostRepository, UserRepository},
     service::{
-        AuthService, CategoryService, CommentService, FileService, PostService, UserService,
+        AuthService, CategoryService, CommentFilterService, CommentService, FileService,
+        MediaService, PostService, PublishScheduler, TagService, UploadGc, UserService,
     },
+    utils::ImageLimits,
 };
 
 #[derive(Clone)]
 pub struct DependenciesInject {
     pub category_service: DynCategoryService,
     pub post_service: DynPostsService,
+    pub tag_service: DynTagService,
     pub comment_service: DynCommentService,
+    pub comment_filter_service: DynCommentFilterService,
     pub user_service: DynUserService,
     pub auth_service: DynAuthService,
     pub file_service: DynFileService,
+    pub media_service: DynMediaService,
+    pub storage: DynStorageBackend,
+    pub publish_scheduler: DynPublishScheduler,
+    pub upload_gc: DynUploadGc,
 }
 
 impl DependenciesInject {
-    pub fn new(pool: DatabaseConnection, hashing: Hashing, jwt_config: JwtConfig) -> Self {
+    pub fn new(
+        pool: DatabaseConnection,
+        hashing: Hashing,
+        jwt_config: JwtConfig,
+        storage: DynStorageBackend,
+        config: &Config,
+    ) -> Self {
         let category_repository =
             Arc::new(CategoryRepository::new(pool.clone())) as DynCategoryRepository;
 
@@ -35,32 +56,103 @@ impl DependenciesInject {
 
         let post_repository = Arc::new(PostRepository::new(pool.clone())) as DynPostsRepository;
 
-        let post_service = Arc::new(PostService::new(post_repository.clone())) as DynPostsService;
-
         let comment_repository =
             Arc::new(CommentRepository::new(pool.clone())) as DynCommentRepository;
-        let comment_service =
-            Arc::new(CommentService::new(comment_repository)) as DynCommentService;
+
+        let comment_filter_repository =
+            Arc::new(CommentFilterRepository::new(pool.clone())) as DynCommentFilterRepository;
+        let mut comment_filters: Vec<DynCommentFilter> = vec![
+            Arc::new(HoneypotFilter),
+            Arc::new(BlocklistFilter::new(comment_filter_repository.clone())),
+            Arc::new(LinkLimitFilter::new(config.comment_filter.max_links)),
+        ];
+        if !config.comment_filter.duplicate_window.is_zero() {
+            comment_filters.push(Arc::new(DuplicateFilter::new(
+                comment_repository.clone(),
+                config.comment_filter.duplicate_window,
+            )));
+        }
+        let comment_filter_service = Arc::new(CommentFilterService::new(
+            comment_filter_repository,
+            comment_filters,
+        )) as DynCommentFilterService;
+
+        let comment_service = Arc::new(CommentService::new(
+            comment_repository,
+            post_repository.clone(),
+            comment_filter_service.clone(),
+            config.comment_max_depth,
+            config.comments_require_approval,
+        )) as DynCommentService;
 
         let user_repository = Arc::new(UserRepository::new(pool.clone())) as DynUserRepository;
 
         let user_service = Arc::new(UserService::new(user_repository.clone())) as DynUserService;
 
+        let tag_repository = Arc::new(TagRepository::new(pool.clone())) as DynTagRepository;
+        let tag_service = Arc::new(TagService::new(tag_repository.clone())) as DynTagService;
+
+        let image_variant_repository =
+            Arc::new(ImageVariantRepository::new(pool.clone())) as DynImageVariantRepository;
+
+        let post_service = Arc::new(PostService::new(
+            post_repository.clone(),
+            user_repository.clone(),
+            tag_repository,
+            image_variant_repository.clone(),
+            &config.media_base_url,
+        )) as DynPostsService;
+
+        let publish_scheduler = Arc::new(PublishScheduler::new(
+            post_repository.clone(),
+            Duration::from_secs(30),
+        )) as DynPublishScheduler;
+
+        let token_repository = Arc::new(TokenRepository::new(pool.clone())) as DynTokenRepository;
+
         let auth_service = Arc::new(AuthService::new(
             user_repository.clone(),
+            token_repository,
             hashing,
             jwt_config,
         ));
 
-        let file_service = Arc::new(FileService::new()) as DynFileService;
+        let media_repository = Arc::new(MediaRepository::new(pool.clone())) as DynMediaRepository;
+
+        let file_service = Arc::new(FileService::new(
+            storage.clone(),
+            image_variant_repository.clone(),
+            media_repository.clone(),
+            ImageLimits::default(),
+            config.image_variants.clone(),
+        )) as DynFileService;
+
+        let upload_gc = Arc::new(UploadGc::new(
+            storage.clone(),
+            media_repository.clone(),
+            image_variant_repository,
+            &config.upload_gc,
+        )) as DynUploadGc;
+
+        let media_service = Arc::new(MediaService::new(
+            media_repository,
+            file_service.clone(),
+            storage.clone(),
+        )) as DynMediaService;
 
         Self {
             category_service,
             post_service,
+            tag_service,
             comment_service,
+            comment_filter_service,
             user_service,
             auth_service,
             file_service,
+            media_service,
+            storage,
+            publish_scheduler,
+            upload_gc,
         }
     }
 }
@@ -0,0 +1,19 @@
+use similar::{ChangeTag, TextDiff};
+
+use crate::domain::{DiffLineResponse, DiffOp};
+
+pub fn line_diff(old: &str, new: &str) -> Vec<DiffLineResponse> {
+    TextDiff::from_lines(old, new)
+        .iter_all_changes()
+        .map(|change| DiffLineResponse {
+            op: match change.tag() {
+                ChangeTag::Equal => DiffOp::Equal,
+                ChangeTag::Insert => DiffOp::Insert,
+                ChangeTag::Delete => DiffOp::Delete,
+            },
+            old_line: change.old_index().map(|index| index + 1),
+            new_line: change.new_index().map(|index| index + 1),
+            text: change.as_str().unwrap_or_default().trim_end_matches(['\r', '\n']).to_string(),
+        })
+        .collect()
+}
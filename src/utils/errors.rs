@@ -1,8 +1,16 @@
-use sea_orm::DbErr;
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::{DbErr, SqlErr};
 use bcrypt::BcryptError;
 use jsonwebtoken::errors::Error as JwtError;
 use thiserror::Error;
-use serde::Serialize;
+use tracing::error;
+use validator::ValidationErrors;
+
+use crate::domain::{FieldError, ProblemDetails};
 
 #[derive(Debug, Error)]
 pub enum AppError {
@@ -16,7 +24,35 @@ pub enum AppError {
     #[error("Not Found: {0}")]
     NotFound(String),
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Invalid status transition: {0}")]
+    InvalidStatusTransition(String),
+
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
+    #[error("Unsupported media type: {0}")]
+    UnsupportedMediaType(String),
+
+    #[error("Validation failed: {0}")]
+    Validation(String),
+
+    #[error("Invalid fields: {0}")]
+    InvalidFields(#[from] ValidationErrors),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
 
     #[error("Token expired")]
     TokenExpiredError,
@@ -24,6 +60,15 @@ pub enum AppError {
     #[error("Token validation error")]
     TokenValidationError,
 
+    #[error("Token revoked")]
+    TokenRevokedError,
+
+    #[error("Invalid refresh token")]
+    InvalidRefreshToken,
+
+    #[error("Refresh token reuse detected")]
+    RefreshTokenReuseDetected,
+
     #[error("Token generation error")]
     TokenGenerationError(#[from] JwtError),
 
@@ -37,12 +82,165 @@ pub enum AppError {
     EmailAlreadyExists,
 }
 
-impl Serialize for AppError {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::ser::Serializer,
-    {
-        serializer.serialize_str(self.to_string().as_ref())
+impl AppError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::DbError(DbErr::RecordNotFound(_)) => StatusCode::NOT_FOUND,
+            AppError::DbError(err) => match err.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => StatusCode::CONFLICT,
+                Some(SqlErr::ForeignKeyConstraintViolation(_)) => StatusCode::CONFLICT,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::InvalidStatusTransition(_)
+            | AppError::Conflict(_)
+            | AppError::EmailAlreadyExists => StatusCode::CONFLICT,
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            AppError::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            AppError::Validation(_) | AppError::InvalidFields(_) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            AppError::Unauthorized(_)
+            | AppError::TokenExpiredError
+            | AppError::TokenValidationError
+            | AppError::TokenRevokedError
+            | AppError::InvalidRefreshToken
+            | AppError::RefreshTokenReuseDetected
+            | AppError::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            AppError::HashingError(_)
+            | AppError::BcryptError(_)
+            | AppError::TokenGenerationError(_)
+            | AppError::StorageError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Stable identifier clients can match on; never change an existing value.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::DbError(DbErr::RecordNotFound(_)) => "not_found",
+            AppError::DbError(err) => match err.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => "conflict",
+                Some(SqlErr::ForeignKeyConstraintViolation(_)) => "constraint_violation",
+                _ => "database_error",
+            },
+            AppError::HashingError(_) | AppError::BcryptError(_) => "hashing_error",
+            AppError::NotFound(_) => "not_found",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::InvalidStatusTransition(_) => "invalid_status_transition",
+            AppError::BadRequest(_) => "bad_request",
+            AppError::PayloadTooLarge(_) => "payload_too_large",
+            AppError::UnsupportedMediaType(_) => "unsupported_media_type",
+            AppError::Validation(_) | AppError::InvalidFields(_) => "validation_failed",
+            AppError::Conflict(_) => "conflict",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::StorageError(_) => "storage_error",
+            AppError::TokenExpiredError => "token_expired",
+            AppError::TokenValidationError => "invalid_token",
+            AppError::TokenRevokedError => "token_revoked",
+            AppError::InvalidRefreshToken => "invalid_refresh_token",
+            AppError::RefreshTokenReuseDetected => "refresh_token_reused",
+            AppError::TokenGenerationError(_) => "token_generation_failed",
+            AppError::InvalidCredentials => "invalid_credentials",
+            AppError::EmailAlreadyExists => "email_already_exists",
+        }
+    }
+
+    /// Human-readable explanation safe to show to clients; internal failures stay generic.
+    pub fn detail(&self) -> String {
+        match self {
+            AppError::DbError(DbErr::RecordNotFound(msg)) => msg.clone(),
+            AppError::DbError(err) => match err.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => {
+                    "The resource conflicts with an existing one".to_string()
+                }
+                Some(SqlErr::ForeignKeyConstraintViolation(_)) => {
+                    "The resource is still referenced or references a missing record".to_string()
+                }
+                _ => "Database error occurred".to_string(),
+            },
+            AppError::HashingError(_) | AppError::BcryptError(_) => {
+                "Error during password hashing".to_string()
+            }
+            AppError::NotFound(msg)
+            | AppError::Forbidden(msg)
+            | AppError::InvalidStatusTransition(msg)
+            | AppError::BadRequest(msg)
+            | AppError::PayloadTooLarge(msg)
+            | AppError::UnsupportedMediaType(msg)
+            | AppError::Validation(msg)
+            | AppError::Conflict(msg)
+            | AppError::Unauthorized(msg) => msg.clone(),
+            AppError::InvalidFields(_) => "Request validation failed".to_string(),
+            AppError::StorageError(_) => "File storage error occurred".to_string(),
+            AppError::TokenExpiredError => "Token has expired".to_string(),
+            AppError::TokenValidationError => "Token validation failed".to_string(),
+            AppError::TokenRevokedError => "Token has been revoked".to_string(),
+            AppError::InvalidRefreshToken => "Invalid refresh token".to_string(),
+            AppError::RefreshTokenReuseDetected => {
+                "Refresh token reuse detected, all sessions were revoked".to_string()
+            }
+            AppError::TokenGenerationError(_) => "Token generation failed".to_string(),
+            AppError::InvalidCredentials => "Invalid credentials".to_string(),
+            AppError::EmailAlreadyExists => "Email already exists".to_string(),
+        }
+    }
+
+    pub fn to_problem(&self) -> ProblemDetails {
+        let status = self.status_code();
+
+        ProblemDetails {
+            problem_type: "about:blank".to_string(),
+            title: status.canonical_reason().unwrap_or("Error").to_string(),
+            status: status.as_u16(),
+            detail: self.detail(),
+            code: self.code().to_string(),
+            errors: self.field_errors(),
+        }
+    }
+
+    fn field_errors(&self) -> Vec<FieldError> {
+        let AppError::InvalidFields(errors) = self else {
+            return Vec::new();
+        };
+
+        let mut fields: Vec<FieldError> = errors
+            .field_errors()
+            .into_iter()
+            .flat_map(|(field, failures)| {
+                failures.iter().map(move |failure| FieldError {
+                    field: field.to_string(),
+                    code: failure.code.to_string(),
+                    message: failure
+                        .message
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| format!("failed `{}` validation", failure.code)),
+                })
+            })
+            .collect();
+
+        // field_errors() is backed by a HashMap; keep the output stable for clients
+        fields.sort_by(|a, b| a.field.cmp(&b.field));
+        fields
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+
+        if status.is_server_error() {
+            error!("Request failed: {}", self);
+        }
+
+        (
+            status,
+            [(header::CONTENT_TYPE, "application/problem+json")],
+            Json(self.to_problem()),
+        )
+            .into_response()
     }
 }
 
@@ -0,0 +1,231 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use image::{
+    codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, ImageDecoder, ImageFormat,
+    ImageReader, Limits,
+};
+use uuid::Uuid;
+
+use crate::utils::AppError;
+
+/// Formats accepted for upload; everything else is rejected by its magic bytes.
+const ALLOWED_IMAGE_FORMATS: [ImageFormat; 3] =
+    [ImageFormat::Jpeg, ImageFormat::Png, ImageFormat::WebP];
+
+const JPEG_QUALITY: u8 = 90;
+
+#[derive(Debug, Clone)]
+pub struct ImageLimits {
+    pub max_bytes: u64,
+    pub max_width: u32,
+    pub max_height: u32,
+}
+
+impl Default for ImageLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: 10 * 1024 * 1024,
+            max_width: 8000,
+            max_height: 8000,
+        }
+    }
+}
+
+/// Which downscaled copies to render next to every uploaded original.
+#[derive(Debug, Clone)]
+pub struct VariantSettings {
+    pub widths: Vec<u32>,
+    /// Also render each width as WebP when the original is another format.
+    pub webp: bool,
+}
+
+impl Default for VariantSettings {
+    fn default() -> Self {
+        Self {
+            widths: vec![320, 768, 1280],
+            webp: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SanitizedImage {
+    pub format: ImageFormat,
+    pub image: DynamicImage,
+}
+
+/// A variant written to a temporary file, ready to be handed to storage.
+#[derive(Debug)]
+pub struct RenderedVariant {
+    pub path: PathBuf,
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Checks the file at `path` against `limits` and rewrites it in place as a
+/// freshly encoded image of the same format, dropping EXIF/GPS and any other
+/// metadata or trailing bytes.
+///
+/// Decoding is CPU bound; call this from `spawn_blocking`.
+pub fn sanitize_image(path: &Path, limits: &ImageLimits) -> Result<SanitizedImage, AppError> {
+    let mut file = File::open(path)
+        .map_err(|e| AppError::StorageError(format!("Failed to open upload: {}", e)))?;
+
+    let size = file
+        .metadata()
+        .map_err(|e| AppError::StorageError(format!("Failed to stat upload: {}", e)))?
+        .len();
+    if size > limits.max_bytes {
+        return Err(AppError::PayloadTooLarge(format!(
+            "Image is {} bytes; the maximum is {} bytes",
+            size, limits.max_bytes
+        )));
+    }
+
+    let mut header = [0u8; 32];
+    let read = file
+        .read(&mut header)
+        .map_err(|e| AppError::StorageError(format!("Failed to read upload: {}", e)))?;
+
+    let format = image::guess_format(&header[..read])
+        .ok()
+        .filter(|format| ALLOWED_IMAGE_FORMATS.contains(format))
+        .ok_or_else(|| {
+            AppError::UnsupportedMediaType(
+                "File content is not a JPEG, PNG or WebP image".to_string(),
+            )
+        })?;
+
+    let file = File::open(path)
+        .map_err(|e| AppError::StorageError(format!("Failed to open upload: {}", e)))?;
+    let (width, height) = ImageReader::with_format(BufReader::new(file), format)
+        .into_dimensions()
+        .map_err(|e| {
+            AppError::Validation(format!(
+                "Image could not be read: {}",
+                e.to_string().trim_end()
+            ))
+        })?;
+
+    // Checked before decoding so oversized images never get allocated.
+    if width > limits.max_width || height > limits.max_height {
+        return Err(AppError::Validation(format!(
+            "Image is {}x{} pixels; the maximum is {}x{}",
+            width, height, limits.max_width, limits.max_height
+        )));
+    }
+
+    let image = decode(path, format, limits)?;
+
+    write(path, &image, format)?;
+
+    Ok(SanitizedImage { format, image })
+}
+
+/// Renders every configured width narrower than the original, in the original
+/// format and optionally as WebP. Originals are never upscaled.
+///
+/// Resizing is CPU bound; call this from `spawn_blocking`.
+pub fn render_variants(
+    original: &SanitizedImage,
+    settings: &VariantSettings,
+) -> Result<Vec<RenderedVariant>, AppError> {
+    let mut widths: Vec<u32> = settings
+        .widths
+        .iter()
+        .copied()
+        .filter(|width| *width > 0 && *width < original.image.width())
+        .collect();
+    widths.sort_unstable();
+    widths.dedup();
+
+    let mut formats = vec![original.format];
+    if settings.webp && original.format != ImageFormat::WebP {
+        formats.push(ImageFormat::WebP);
+    }
+
+    let mut rendered: Vec<RenderedVariant> = Vec::with_capacity(widths.len() * formats.len());
+    for width in widths {
+        let resized = original
+            .image
+            .resize(width, u32::MAX, FilterType::CatmullRom);
+
+        for format in &formats {
+            let path = std::env::temp_dir().join(format!("variant-{}", Uuid::new_v4()));
+
+            if let Err(e) = write(&path, &resized, *format) {
+                let _ = std::fs::remove_file(&path);
+                for variant in &rendered {
+                    let _ = std::fs::remove_file(&variant.path);
+                }
+                return Err(e);
+            }
+
+            rendered.push(RenderedVariant {
+                path,
+                format: *format,
+                width: resized.width(),
+                height: resized.height(),
+            });
+        }
+    }
+
+    Ok(rendered)
+}
+
+fn decode(
+    path: &Path,
+    format: ImageFormat,
+    limits: &ImageLimits,
+) -> Result<DynamicImage, AppError> {
+    let file = File::open(path)
+        .map_err(|e| AppError::StorageError(format!("Failed to open upload: {}", e)))?;
+
+    let mut reader = ImageReader::with_format(BufReader::new(file), format);
+    let mut decoder_limits = Limits::default();
+    decoder_limits.max_image_width = Some(limits.max_width);
+    decoder_limits.max_image_height = Some(limits.max_height);
+    reader.limits(decoder_limits);
+
+    let invalid = |e: image::ImageError| {
+        AppError::Validation(format!(
+            "Image could not be decoded: {}",
+            e.to_string().trim_end()
+        ))
+    };
+
+    let mut decoder = reader.into_decoder().map_err(invalid)?;
+    // The EXIF orientation is about to be stripped, so bake it into the pixels.
+    let orientation = decoder.orientation().map_err(invalid)?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(invalid)?;
+    image.apply_orientation(orientation);
+
+    Ok(image)
+}
+
+fn write(path: &Path, image: &DynamicImage, format: ImageFormat) -> Result<(), AppError> {
+    let file = File::create(path)
+        .map_err(|e| AppError::StorageError(format!("Failed to write image: {}", e)))?;
+    let mut writer = BufWriter::new(file);
+
+    let result = match format {
+        ImageFormat::Jpeg => image
+            .to_rgb8()
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut writer, JPEG_QUALITY)),
+        ImageFormat::WebP => {
+            DynamicImage::ImageRgba8(image.to_rgba8()).write_to(&mut writer, ImageFormat::WebP)
+        }
+        _ => image.write_to(&mut writer, format),
+    };
+
+    result.map_err(|e| AppError::StorageError(format!("Failed to encode image: {}", e)))?;
+
+    writer
+        .flush()
+        .map_err(|e| AppError::StorageError(format!("Failed to write image: {}", e)))
+}
@@ -1,9 +1,19 @@
+mod diff;
 mod errors;
 mod di;
+mod imaging;
 mod log;
+mod permission;
+mod search;
 mod slug;
 
+pub use self::diff::line_diff;
 pub use self::errors::{AppError, ConnectionManagerError};
 pub use self::di::DependenciesInject;
+pub use self::imaging::{
+    render_variants, sanitize_image, ImageLimits, RenderedVariant, SanitizedImage, VariantSettings,
+};
 pub use self::log::tracing;
-pub use self::slug::generate_slug;
\ No newline at end of file
+pub use self::permission::Permission;
+pub use self::search::{build_tsquery, contains_pattern};
+pub use self::slug::generate_slug;
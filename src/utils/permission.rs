@@ -0,0 +1,42 @@
+use crate::entities::sea_orm_active_enums::Role;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    WriteComments,
+    WritePosts,
+    PublishPosts,
+    ModerateComments,
+    ManageCategories,
+    ManageTags,
+    ManageUsers,
+}
+
+impl Role {
+    // Higher levels include every permission of the lower ones.
+    pub fn level(&self) -> u8 {
+        match self {
+            Role::Reader => 0,
+            Role::Author => 1,
+            Role::Editor => 2,
+            Role::Admin => 3,
+        }
+    }
+
+    pub fn at_least(&self, min_role: Role) -> bool {
+        self.level() >= min_role.level()
+    }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        let min_role = match permission {
+            Permission::WriteComments => Role::Reader,
+            Permission::WritePosts => Role::Author,
+            Permission::PublishPosts
+            | Permission::ModerateComments
+            | Permission::ManageCategories
+            | Permission::ManageTags => Role::Editor,
+            Permission::ManageUsers => Role::Admin,
+        };
+
+        self.at_least(min_role)
+    }
+}
@@ -0,0 +1,99 @@
+enum Token {
+    Term { words: Vec<String>, negated: bool, prefix: bool },
+    Or,
+}
+
+/// `LIKE`/`ILIKE` pattern matching `term` anywhere, with its wildcards escaped.
+pub fn contains_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{}%", escaped)
+}
+
+/// Turns free-form search input into a Postgres `to_tsquery` expression.
+///
+/// Supports `"quoted phrases"`, `-negation`, `prefix*` and `or`; all other terms must match.
+/// Only alphanumeric characters survive, so the result is always a valid tsquery.
+pub fn build_tsquery(input: &str) -> Option<String> {
+    let mut query = String::new();
+    let mut pending_or = false;
+
+    for token in tokenize(input) {
+        match token {
+            Token::Or => pending_or = !query.is_empty(),
+            Token::Term { words, negated, prefix } => {
+                let mut term = words.join(" <-> ");
+                if prefix {
+                    term.push_str(":*");
+                }
+                if words.len() > 1 {
+                    term = format!("({})", term);
+                }
+                if negated {
+                    term = format!("!{}", term);
+                }
+
+                if !query.is_empty() {
+                    query.push_str(if pending_or { " | " } else { " & " });
+                }
+                query.push_str(&term);
+                pending_or = false;
+            }
+        }
+    }
+
+    (!query.is_empty()).then_some(query)
+}
+
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let negated = c == '-';
+        if negated {
+            chars.next();
+        }
+
+        let raw: String = if chars.peek() == Some(&'"') {
+            chars.next();
+            chars.by_ref().take_while(|&c| c != '"').collect()
+        } else {
+            let mut raw = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                raw.push(c);
+                chars.next();
+            }
+            raw
+        };
+
+        if !negated && raw.eq_ignore_ascii_case("or") {
+            tokens.push(Token::Or);
+            continue;
+        }
+
+        let prefix = raw.ends_with('*');
+        let words: Vec<String> = raw
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+
+        if !words.is_empty() {
+            tokens.push(Token::Term { words, negated, prefix });
+        }
+    }
+
+    tokens
+}
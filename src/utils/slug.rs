@@ -1,15 +1,19 @@
-pub fn generate_slug(title: &str) -> String {
-    // Convert title to lowercase
-    let title = title.to_lowercase();
+use deunicode::deunicode;
 
-    // Replace spaces with hyphens
-    let mut slug = title.replace(' ', "-");
+pub fn generate_slug(title: &str) -> String {
+    // Transliterate non-ASCII characters (e.g. "Łódź" -> "Lodz", "北京" -> "Bei Jing")
+    let title = deunicode(title).to_lowercase();
 
-    // Remove characters that are not alphanumeric or hyphens
-    slug = slug.chars().filter(|c| c.is_alphanumeric() || *c == '-').collect();
+    // Replace every run of non-alphanumeric characters with a single hyphen
+    let mut slug = String::with_capacity(title.len());
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
 
     // Trim leading or trailing hyphens
-    slug = slug.trim_matches('-').to_string();
-
-    slug
-}
\ No newline at end of file
+    slug.trim_matches('-').to_string()
+}